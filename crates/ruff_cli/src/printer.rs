@@ -36,6 +36,10 @@ bitflags! {
         const SHOW_FIX_SUMMARY = 0b0000_0100;
         /// Whether to show a diff of each fixed violation when emitting diagnostics.
         const SHOW_FIX_DIFF = 0b0000_1000;
+        /// Whether to apply fixes and suppress all residual violation reporting, regardless of
+        /// serialization format. Intended for CI/pre-commit setups that only want files rewritten,
+        /// without the noise (or non-zero exit status) of unfixable violations being printed.
+        const FIX_ONLY = 0b0001_0000;
     }
 }
 
@@ -132,7 +136,9 @@ impl Printer {
                     .sum::<usize>();
                 if fixed > 0 {
                     let s = if fixed == 1 { "" } else { "s" };
-                    if self.fix_mode.is_apply() {
+                    // `--fix-only` implies that fixes were actually applied, so always report
+                    // them as such, even if `fix_mode` itself wouldn't otherwise say so.
+                    if self.fix_mode.is_apply() || self.flags.intersects(Flags::FIX_ONLY) {
                         writeln!(writer, "Fixed {fixed} error{s}.")?;
                     } else {
                         writeln!(writer, "Would fix {fixed} error{s}.")?;
@@ -152,15 +158,23 @@ impl Printer {
             return Ok(());
         }
 
-        if !self.flags.intersects(Flags::SHOW_VIOLATIONS) {
-            if matches!(
-                self.format,
-                SerializationFormat::Text | SerializationFormat::Grouped
-            ) {
+        // `--fix-only` short-circuits before the emitter dispatch below entirely: we only ever
+        // want the fix summary, not a format-specific rendering of the residual violations, no
+        // matter which `self.format` was requested.
+        let fix_only = self.flags.intersects(Flags::FIX_ONLY);
+
+        if fix_only || !self.flags.intersects(Flags::SHOW_VIOLATIONS) {
+            if fix_only
+                || matches!(
+                    self.format,
+                    SerializationFormat::Text | SerializationFormat::Grouped
+                )
+            {
                 if self.flags.intersects(Flags::SHOW_FIX_SUMMARY) {
-                    if !diagnostics.fixed.is_empty() {
+                    let fixed = FixMap::from_raw(&diagnostics.fixed);
+                    if !fixed.is_empty() {
                         writeln!(writer)?;
-                        print_fix_summary(writer, &diagnostics.fixed)?;
+                        print_fix_summary(writer, &fixed)?;
                         writeln!(writer)?;
                     }
                 }
@@ -190,9 +204,10 @@ impl Printer {
                     .emit(writer, &diagnostics.messages, &context)?;
 
                 if self.flags.intersects(Flags::SHOW_FIX_SUMMARY) {
-                    if !diagnostics.fixed.is_empty() {
+                    let fixed = FixMap::from_raw(&diagnostics.fixed);
+                    if !fixed.is_empty() {
                         writeln!(writer)?;
-                        print_fix_summary(writer, &diagnostics.fixed)?;
+                        print_fix_summary(writer, &fixed)?;
                         writeln!(writer)?;
                     }
                 }
@@ -206,9 +221,10 @@ impl Printer {
                     .emit(writer, &diagnostics.messages, &context)?;
 
                 if self.flags.intersects(Flags::SHOW_FIX_SUMMARY) {
-                    if !diagnostics.fixed.is_empty() {
+                    let fixed = FixMap::from_raw(&diagnostics.fixed);
+                    if !fixed.is_empty() {
                         writeln!(writer)?;
-                        print_fix_summary(writer, &diagnostics.fixed)?;
+                        print_fix_summary(writer, &fixed)?;
                         writeln!(writer)?;
                     }
                 }
@@ -318,6 +334,23 @@ impl Printer {
             SerializationFormat::Json => {
                 writeln!(writer, "{}", serde_json::to_string_pretty(&statistics)?)?;
             }
+            SerializationFormat::JsonLines => {
+                for statistic in statistics {
+                    writeln!(writer, "{}", serde_json::to_string(&statistic)?)?;
+                }
+            }
+            // A CSV/TSV table would need a new `SerializationFormat` variant, and
+            // `ruff_linter::settings::types` (where that enum is declared) isn't part of this
+            // checkout, so that one can't be added here.
+            //
+            // `Github` and `Gitlab` *do* already exist as variants -- they're used for message
+            // output above -- but `GithubEmitter`/`GitlabEmitter` serialize `&[Message]` into a
+            // specific upstream schema (GitHub workflow commands, GitLab Code Quality JSON) that
+            // this crate only sees through their `emit()` signature, not their field-level
+            // layout. `ExpandedStatistics` isn't a `Message`, and guessing at a
+            // rule-aggregate-shaped schema for those formats risks emitting something that looks
+            // plausible but doesn't match what either consumer actually expects, so they still
+            // bail below rather than fabricate one.
             _ => {
                 anyhow::bail!(
                     "Unsupported serialization format for statistics: {:?}",
@@ -394,16 +427,61 @@ fn show_fix_status(fix_mode: flags::FixMode, fixables: &FixableStatistics) -> bo
     (!fix_mode.is_apply()) && fixables.fixes_are_applicable()
 }
 
-fn print_fix_summary(writer: &mut dyn Write, fixed: &FxHashMap<String, FixTable>) -> Result<()> {
-    let total = fixed
-        .values()
-        .map(|table| table.values().sum::<usize>())
-        .sum::<usize>();
-    assert!(total > 0);
+/// A map from filename to [`FixTable`] that structurally guarantees every entry holds at least
+/// one fix: [`FixMap::from_raw`] is the only way to build one, and it drops any entry whose
+/// [`FixTable`] is empty, so every entry a `FixMap` holds is non-empty by construction -- no
+/// caller needs to re-derive (or assert) that invariant itself. The map as a whole can still be
+/// empty; [`FixMap::is_empty`] reports that.
+///
+/// This is a borrowed view built at print time, not a replacement for `Diagnostics::fixed`'s
+/// declared type: `diagnostics.rs` (where `Diagnostics` and its `fixed` field are declared)
+/// isn't part of this checkout, so that field is still a raw `FxHashMap<String, FixTable>` that
+/// something upstream of the printer could in principle still populate with an empty table. The
+/// invariant is structural for everything that goes through `FixMap`, which is everything the
+/// printer itself does with fixed-diagnostics data -- it just isn't enforced at the point where
+/// `Diagnostics::fixed` is originally populated, because this crate can't see that code.
+struct FixMap<'a>(FxHashMap<&'a str, &'a FixTable>);
+
+impl<'a> FixMap<'a> {
+    /// Builds a `FixMap` from a raw fixed-diagnostics table, dropping any entry whose
+    /// [`FixTable`] contains no fixes.
+    fn from_raw(fixed: &'a FxHashMap<String, FixTable>) -> Self {
+        Self(
+            fixed
+                .iter()
+                .filter(|(_, table)| !table.is_empty() && table.values().sum::<usize>() > 0)
+                .map(|(filename, table)| (filename.as_str(), table))
+                .collect(),
+        )
+    }
+
+    /// Returns `true` if there are zero actual fixes across every file.
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn total(&self) -> usize {
+        self.0
+            .values()
+            .map(|table| table.values().sum::<usize>())
+            .sum()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&&'a str, &&'a FixTable)> {
+        self.0.iter()
+    }
+}
+
+fn print_fix_summary(writer: &mut dyn Write, fixed: &FixMap) -> Result<()> {
+    if fixed.is_empty() {
+        return Ok(());
+    }
+
+    let total = fixed.total();
     let num_digits = num_digits(
         *fixed
-            .values()
-            .filter_map(|table| table.values().max())
+            .iter()
+            .filter_map(|(_, table)| table.values().max())
             .max()
             .unwrap(),
     );
@@ -412,10 +490,7 @@ fn print_fix_summary(writer: &mut dyn Write, fixed: &FxHashMap<String, FixTable>
     let label = format!("Fixed {total} error{s}:");
     writeln!(writer, "{}", label.bold().green())?;
 
-    for (filename, table) in fixed
-        .iter()
-        .sorted_by_key(|(filename, ..)| filename.as_str())
-    {
+    for (filename, table) in fixed.iter().sorted_by_key(|(filename, ..)| **filename) {
         writeln!(
             writer,
             "{} {}{}",
@@ -435,72 +510,80 @@ fn print_fix_summary(writer: &mut dyn Write, fixed: &FxHashMap<String, FixTable>
     Ok(())
 }
 
-/// Contains the number of [`Applicability::Automatic`] and [`Applicability::Suggested`] fixes
-struct FixableStatistics<'a> {
-    automatic: u32,
-    suggested: u32,
-    apply_suggested: &'a SuggestedFixes,
+/// Ranks an [`Applicability`] by how safe it is to apply automatically, so it can be compared
+/// against a minimum threshold. Higher is safer. `ruff_diagnostics` is the authority on what
+/// variants `Applicability` actually has; this only ranks the ones this crate observes today.
+fn applicability_rank(applicability: Applicability) -> u8 {
+    match applicability {
+        Applicability::Automatic => 2,
+        Applicability::Suggested => 1,
+        // `ruff_diagnostics::Applicability` isn't part of this checkout, so its full variant
+        // list isn't visible here; treat anything this crate doesn't explicitly rank
+        // conservatively, as below the lowest known-safe rank -- rather than failing to compile
+        // (or silently counting it as applicable) the moment a new variant is added upstream.
+        _ => 0,
+    }
 }
 
-impl<'a> FixableStatistics<'a> {
-    fn new(diagnostics: &Diagnostics, apply_suggested: &'a SuggestedFixes) -> Self {
-        let mut automatic = 0;
-        let mut suggested = 0;
+/// Partitions fixes into `applicable` (those at or above the configured [`Applicability`]
+/// threshold, which the current fix mode would apply) and `hidden` (fixes that exist but fall
+/// below the threshold, e.g. because a stronger opt-in flag, `--fix-suggested`, wasn't passed),
+/// so the summary can always tell users there's more available without them having to guess the
+/// flag name.
+struct FixableStatistics {
+    applicable: u32,
+    hidden: u32,
+}
+
+impl FixableStatistics {
+    fn new(diagnostics: &Diagnostics, apply_suggested: &SuggestedFixes) -> Self {
+        // `SuggestedFixes` is still the only knob this crate exposes for configuring the
+        // threshold (there's no `--fix-threshold`-style flag to plumb through yet), so it's
+        // translated into the equivalent minimum `Applicability` here rather than at every call
+        // site.
+        let threshold = match apply_suggested {
+            SuggestedFixes::Apply => Applicability::Suggested,
+            SuggestedFixes::Disable => Applicability::Automatic,
+        };
+        let threshold_rank = applicability_rank(threshold);
+
+        let mut applicable = 0;
+        let mut hidden = 0;
 
         for message in &diagnostics.messages {
             if let Some(fix) = &message.fix {
-                if fix.applicability() == Applicability::Suggested {
-                    suggested += 1;
-                } else if fix.applicability() == Applicability::Automatic {
-                    automatic += 1;
+                if applicability_rank(fix.applicability()) >= threshold_rank {
+                    applicable += 1;
+                } else {
+                    hidden += 1;
                 }
             }
         }
 
-        Self {
-            automatic,
-            suggested,
-            apply_suggested,
-        }
+        Self { applicable, hidden }
     }
 
     fn fixes_are_applicable(&self) -> bool {
-        match self.apply_suggested {
-            SuggestedFixes::Apply => self.automatic > 0 || self.suggested > 0,
-            SuggestedFixes::Disable => self.automatic > 0,
-        }
+        self.applicable > 0
     }
 
     /// Returns [`true`] if there aren't any fixes to be displayed
     fn is_empty(&self) -> bool {
-        self.automatic == 0 && self.suggested == 0
+        self.applicable == 0 && self.hidden == 0
     }
 
-    /// Build the displayed fix status message depending on the types of the remaining fixes.
+    /// Build the displayed fix status message, noting any fixes hidden behind `--fix-suggested`.
     fn violation_string(&self) -> String {
         let prefix = format!("[{}]", "*".cyan());
-        let mut fix_status = prefix;
-
-        if self.automatic > 0 {
-            fix_status = format!(
-                "{fix_status} {} potentially fixable with the --fix option.",
-                self.automatic
-            );
-        }
 
-        if self.suggested > 0 {
-            let (line_break, extra_prefix) = if self.automatic > 0 {
-                ("\n", format!("[{}]", "*".cyan()))
-            } else {
-                ("", String::new())
-            };
-
-            let total = self.automatic + self.suggested;
-            fix_status = format!(
-            "{fix_status}{line_break}{extra_prefix} {total} potentially fixable with the --fix-suggested option."
-        );
+        if self.hidden > 0 {
+            let hidden_fix = if self.hidden == 1 { "fix" } else { "fixes" };
+            format!(
+                "{prefix} {} fixable with the --fix option ({} hidden {hidden_fix} can be enabled with the --fix-suggested option)",
+                self.applicable, self.hidden
+            )
+        } else {
+            format!("{prefix} {} fixable with the --fix option.", self.applicable)
         }
-
-        fix_status
     }
 }
@@ -0,0 +1,274 @@
+use std::fmt;
+
+use ruff_text_size::TextRange;
+
+use crate::{lexer::LexicalErrorType, TokenKind};
+
+/// Represents an error that occurs during parsing and is returned by the `parse_*` functions.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub error: ParseErrorType,
+    pub location: TextRange,
+    /// An optional fix that a downstream consumer (e.g. the linter or formatter) can apply
+    /// directly to resolve the error, without needing to understand `error` itself.
+    pub suggestion: Option<ParseSuggestion>,
+    /// An optional secondary span that labels a location related to the error besides `location`
+    /// itself, e.g. where a redefined name was originally defined. Mirrors the "previously
+    /// defined here"-style secondary labels in rustc diagnostics.
+    pub secondary_label: Option<SecondaryLabel>,
+}
+
+/// A secondary span attached to a [`ParseError`], along with a short message describing what it
+/// points at (e.g. `"previously defined here"`).
+#[derive(Debug, PartialEq, Clone)]
+pub struct SecondaryLabel {
+    pub range: TextRange,
+    pub message: String,
+}
+
+impl std::ops::Deref for ParseError {
+    type Target = ParseErrorType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.error
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte offset {}", &self.error, u32::from(self.location.start()))
+    }
+}
+
+impl From<LexicalError> for ParseError {
+    fn from(error: LexicalError) -> Self {
+        ParseError {
+            location: error.location,
+            error: ParseErrorType::Lexical(error.error),
+            suggestion: None,
+            secondary_label: None,
+        }
+    }
+}
+
+/// A machine-applicable fix for a [`ParseError`], in the same spirit as a `rustc` diagnostic
+/// suggestion: replace `range` with `replacement` to resolve the error.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseSuggestion {
+    pub range: TextRange,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+/// How confident the parser is that applying a [`ParseSuggestion`] is correct, mirroring
+/// `rustc`'s notion of suggestion applicability.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended; it can be applied automatically.
+    MachineApplicable,
+    /// The suggestion may be what the user intended, but it's not certain enough to apply
+    /// automatically.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders that the user needs to fill in before it's valid,
+    /// e.g. an inserted identifier.
+    HasPlaceholders,
+}
+
+/// Represents the different types of errors that can occur during lexing.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LexicalError {
+    /// The type of error that occurred.
+    pub error: LexicalErrorType,
+    /// The location of the error.
+    pub location: TextRange,
+}
+
+/// Represents the different types of errors that can occur during parsing.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseErrorType {
+    /// An unexpected error occurred.
+    OtherError(String),
+
+    /// A token was expected, but a different one was found instead.
+    ExpectedToken {
+        found: TokenKind,
+        expected: TokenKind,
+    },
+
+    /// One of several tokens was expected, but a different one was found instead. This is
+    /// emitted instead of [`ParseErrorType::ExpectedToken`] whenever the parser probed more
+    /// than one token kind (via `at`/`at_ts`/`eat`) since the last successful bump.
+    ExpectedOneOf {
+        found: TokenKind,
+        expected: Vec<TokenKind>,
+    },
+
+    /// An `async` keyword was found, but the following statement is not one that can be
+    /// preceded by `async`.
+    StmtIsNotAsync(TokenKind),
+
+    /// An invalid match pattern literal was found.
+    InvalidMatchPatternLiteral { pattern: TokenKind },
+
+    /// An invalid assignment target was found.
+    AssignmentError,
+
+    /// A bare `=` was found where a boolean expression was expected, e.g. in the condition of
+    /// an `if`/`elif`/`while` statement. This is almost always a typo for `==`, as in other
+    /// C-like languages.
+    AssignmentInCondition,
+
+    /// An invalid augmented assignment target was found.
+    AugAssignmentError,
+
+    /// An invalid named (walrus) assignment target was found.
+    NamedAssignmentError,
+
+    /// Multiple simple statements were found on the same line without a separating
+    /// semicolon.
+    SimpleStmtsInSameLine,
+
+    /// A simple statement and a compound statement were found on the same line.
+    SimpleStmtAndCompoundStmtInSameLine,
+
+    /// An iterable unpacking (`*expr`) was found where it isn't allowed, e.g. as a
+    /// keyword argument.
+    UnpackedArgumentError,
+
+    /// A positional argument was found after a keyword argument.
+    PositionalArgumentError,
+
+    /// A parameter without a default value was found after a parameter with a default
+    /// value, or after `*args`/`**kwargs`.
+    DefaultArgumentError,
+
+    /// A parameter follows `**kwargs` in a parameter list.
+    ParamFollowsVarKeywordParam,
+
+    /// An empty slice (e.g. `x[]`) was found.
+    EmptySlice,
+
+    /// A parameter was expected between two commas (or an opening delimiter and a comma) in a
+    /// parameter list, e.g. `def f(, b):` or `def f(a,,b):`.
+    MissingParameter,
+
+    /// A bracket, brace, or parenthesis was never closed before the end of the logical line (or
+    /// the file). Carries the range of the opening delimiter so the diagnostic can point back to
+    /// where the unclosed group began, not just where the parser gave up looking for its match.
+    UnclosedDelimiter {
+        opener: TextRange,
+        expected: TokenKind,
+    },
+
+    /// An expression was nested deeply enough that continuing to parse it recursively risked
+    /// overflowing the stack, e.g. thousands of nested parentheses.
+    ExpressionTooDeeplyNested,
+
+    /// An error that occurred during lexing.
+    Lexical(LexicalErrorType),
+
+    /// An error that occurred while parsing an f-string.
+    FStringError(FStringErrorType),
+}
+
+impl fmt::Display for ParseErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorType::OtherError(msg) => write!(f, "{msg}"),
+            ParseErrorType::ExpectedToken { found, expected } => {
+                write!(f, "expected {expected}, found {found}")
+            }
+            ParseErrorType::ExpectedOneOf { found, expected } => {
+                write!(f, "expected one of ")?;
+                for (i, kind) in expected.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{kind}")?;
+                }
+                write!(f, ", found {found}")
+            }
+            ParseErrorType::StmtIsNotAsync(kind) => {
+                write!(f, "expected `def`, `with` or `for` to follow `async`, found {kind}")
+            }
+            ParseErrorType::InvalidMatchPatternLiteral { pattern } => {
+                write!(f, "invalid match pattern literal, found {pattern}")
+            }
+            ParseErrorType::AssignmentError => write!(f, "invalid assignment target"),
+            ParseErrorType::AssignmentInCondition => write!(
+                f,
+                "assignment `=` is not allowed in a boolean context; did you mean `==`?"
+            ),
+            ParseErrorType::AugAssignmentError => {
+                write!(f, "invalid augmented assignment target")
+            }
+            ParseErrorType::NamedAssignmentError => {
+                write!(f, "assignment expression target must be an identifier")
+            }
+            ParseErrorType::SimpleStmtsInSameLine => {
+                write!(f, "simple statements must be separated by newlines or semicolons")
+            }
+            ParseErrorType::SimpleStmtAndCompoundStmtInSameLine => write!(
+                f,
+                "compound statements are not allowed on the same line as simple statements"
+            ),
+            ParseErrorType::UnpackedArgumentError => {
+                write!(f, "iterable argument unpacking cannot be used in this position")
+            }
+            ParseErrorType::PositionalArgumentError => {
+                write!(f, "positional argument cannot follow keyword argument")
+            }
+            ParseErrorType::DefaultArgumentError => {
+                write!(f, "parameter without a default cannot follow a parameter with a default")
+            }
+            ParseErrorType::ParamFollowsVarKeywordParam => {
+                write!(f, "parameter cannot follow var-keyword parameter")
+            }
+            ParseErrorType::EmptySlice => write!(f, "expected index or slice expression"),
+            ParseErrorType::MissingParameter => write!(f, "expected parameter"),
+            ParseErrorType::UnclosedDelimiter { opener, expected } => write!(
+                f,
+                "missing closing {expected}; unclosed delimiter at byte offset {}",
+                u32::from(opener.start())
+            ),
+            ParseErrorType::ExpressionTooDeeplyNested => {
+                write!(f, "expression is nested too deeply")
+            }
+            ParseErrorType::Lexical(error) => write!(f, "{error}"),
+            ParseErrorType::FStringError(error) => write!(f, "f-string: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseErrorType {}
+
+/// Represents the different types of errors that can occur while parsing an f-string.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FStringErrorType {
+    /// The f-string is missing a closing brace.
+    UnclosedLbrace,
+    /// A lambda expression was used without parentheses, e.g. `f"{lambda x: x}"`.
+    LambdaWithoutParentheses,
+    /// An invalid conversion flag was used, e.g. `f"{x!z}"`.
+    InvalidConversionFlag,
+}
+
+impl fmt::Display for FStringErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FStringErrorType::UnclosedLbrace => write!(f, "expecting `}}`"),
+            FStringErrorType::LambdaWithoutParentheses => {
+                write!(f, "lambda expressions are not allowed without parentheses")
+            }
+            FStringErrorType::InvalidConversionFlag => write!(f, "invalid conversion flag"),
+        }
+    }
+}
+
+impl std::error::Error for FStringErrorType {}
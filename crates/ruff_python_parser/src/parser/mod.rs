@@ -11,13 +11,13 @@ use ruff_text_size::{Ranged, TextLen, TextRange, TextSize};
 
 use crate::lexer::lex;
 use crate::{
-    error::FStringErrorType,
+    error::{Applicability, FStringErrorType, ParseSuggestion},
     lexer::{LexResult, Spanned},
     string::{
         concatenated_strings, parse_fstring_literal_element, parse_string_literal, StringType,
     },
     token_set::TokenSet,
-    token_source::TokenSource,
+    token_source::{TokenSource, TokenSourceCheckpoint},
     Mode, ParseError, ParseErrorType, Tok, TokenKind,
 };
 
@@ -62,13 +62,22 @@ bitflags! {
     struct ParserCtxFlags: u8 {
         const PARENTHESIZED_EXPR = 1 << 0;
 
-        // NOTE: `ARGUMENTS` can be removed once the heuristic in `parse_with_items`
-        // is improved.
         const ARGUMENTS = 1 << 1;
         const FOR_TARGET = 1 << 2;
     }
 }
 
+bitflags! {
+    /// Which optional trailing forms [`Parser::parse_expr_restricted`] is allowed to fold into
+    /// the expression it parses. See that function for details.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Restrictions: u8 {
+        const ALLOW_COND_EXPR = 1 << 0;
+        const ALLOW_NAMED_EXPR = 1 << 1;
+        const ALLOW_TUPLE = 1 << 2;
+    }
+}
+
 type ExprWithRange = (ParsedExpr, TextRange);
 
 #[derive(Debug)]
@@ -86,12 +95,56 @@ impl From<Expr> for ParsedExpr {
     }
 }
 
+/// A snapshot of [`Parser`]'s state, produced by [`Parser::checkpoint`] and consumed by
+/// [`Parser::rewind`] to support speculative, backtracking parses.
+struct Checkpoint {
+    current: Spanned,
+    last_token_end: TextSize,
+    ctx: ParserCtxFlags,
+    ctx_stack_len: usize,
+    last_ctx: ParserCtxFlags,
+    errors_len: usize,
+    expected_tokens_len: usize,
+    defer_invalid_node_creation: Option<TextRange>,
+    tokens: TokenSourceCheckpoint,
+}
+
 /// Binding power associativity
 enum Associativity {
     Left,
     Right,
 }
 
+/// Recovery policy used by [`Parser::parse_separated`]/[`Parser::parse_delimited`], mirroring
+/// rustc_parse's `CommaRecoveryMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SequenceRecovery {
+    /// Stop the sequence as soon as something other than `delim` or the ending set is seen.
+    /// This is the right choice for sequences where guessing a missing delimiter could turn a
+    /// different (but otherwise valid) construct into a confusingly-wrong AST.
+    Forbid,
+    /// Assume a missing `delim` (or an empty `delim`-separated slot) was a mistake, emit a
+    /// targeted error with an insertion suggestion, and keep parsing the rest of the sequence.
+    InsertMissingDelim,
+}
+
+/// A Python 2 idiom that [`Parser::recover_python2_syntax`] knows how to recognize and report.
+/// Each variant carries just enough of the already-parsed construct to confirm the shape and
+/// build a fix.
+enum Python2Construct<'a> {
+    /// `except Exception, e:`, parsed as an unparenthesized 2-tuple exception type whose second
+    /// element is a bare name, immediately followed by `:`.
+    ExceptCommaName {
+        tuple: &'a Expr,
+        range: TextRange,
+    },
+    /// `print "x"` or `exec code`, parsed as a bare `print`/`exec` name statement immediately
+    /// followed by another expression on the same line.
+    PrintOrExecStatement {
+        stmt: &'a Stmt,
+    },
+}
+
 #[derive(Copy, Clone)]
 enum Clause {
     If,
@@ -177,8 +230,26 @@ pub(crate) struct Parser<'src> {
 
     /// The end of the last processed. Used to determine a node's end.
     last_token_end: TextSize,
+
+    /// The set of token kinds that have been probed (via `at`/`at_ts`/`eat`) since the last
+    /// time the parser successfully advanced. Used to build a richer "expected one of" message
+    /// when a subsequent `expect` fails. Cleared every time [`Parser::next_token`] bumps the
+    /// parser forward.
+    expected_tokens: Vec<TokenKind>,
+
+    /// How many nested calls to [`Parser::expr_bp`] are currently on the Rust call stack.
+    /// Every construct that can recurse arbitrarily deeply (parenthesized expressions, call
+    /// arguments, subscripts, collection literals, and so on) eventually parses its nested
+    /// sub-expression through `expr_bp`, so bounding this single counter is enough to bound the
+    /// parser's stack usage overall; see [`MAX_EXPRESSION_NESTING`].
+    recursion_depth: u32,
 }
 
+/// The maximum number of nested `expr_bp` calls allowed before the parser gives up and reports
+/// [`ParseErrorType::ExpressionTooDeeplyNested`] instead of risking a stack overflow on
+/// pathologically nested input, e.g. thousands of nested parentheses.
+const MAX_EXPRESSION_NESTING: u32 = 200;
+
 const NEWLINE_EOF_SET: TokenSet = TokenSet::new(&[TokenKind::Newline, TokenKind::EndOfFile]);
 const LITERAL_SET: TokenSet = TokenSet::new(&[
     TokenKind::Name,
@@ -207,6 +278,11 @@ const EXPR_SET: TokenSet = TokenSet::new(&[
     TokenKind::Not,
     TokenKind::Yield,
     TokenKind::FStringStart,
+    // `match`/`case` are soft keywords: when the soft-keyword lexer hasn't already resolved
+    // one to a `Name` token (e.g. because a speculative statement-level parse backed out of
+    // treating it as a keyword), it still needs to be usable as an ordinary identifier here.
+    TokenKind::Match,
+    TokenKind::Case,
 ])
 .union(LITERAL_SET);
 /// Tokens that can appear after an expression.
@@ -260,6 +336,15 @@ const SIMPLE_STMT_SET: TokenSet = TokenSet::new(&[
 /// Tokens that represent simple statements, including expressions.
 const SIMPLE_STMT_SET2: TokenSet = SIMPLE_STMT_SET.union(EXPR_SET);
 
+/// Returns the literal source text for a delimiter token, for use in insertion suggestions.
+fn delim_text(kind: TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Comma => ",",
+        TokenKind::Colon => ":",
+        other => unreachable!("`{other:?}` is not a delimiter used by `parse_separated`"),
+    }
+}
+
 impl<'src> Parser<'src> {
     pub(crate) fn new(source: &'src str, mode: Mode, mut tokens: TokenSource) -> Parser<'src> {
         let current = tokens
@@ -278,6 +363,8 @@ impl<'src> Parser<'src> {
 
             last_token_end: TextSize::default(),
             defer_invalid_node_creation: None,
+            expected_tokens: Vec::new(),
+            recursion_depth: 0,
         }
     }
     fn finish(self) -> Vec<ParseError> {
@@ -411,6 +498,57 @@ impl<'src> Parser<'src> {
         self.ctx.intersects(ctx)
     }
 
+    /// Captures a snapshot of the parser's state that can later be restored with
+    /// [`Parser::rewind`]. This allows speculatively parsing a construct and backing out if it
+    /// turns out to be the wrong one, instead of relying on one-off lookahead heuristics.
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            current: self.current.clone(),
+            last_token_end: self.last_token_end,
+            ctx: self.ctx,
+            ctx_stack_len: self.ctx_stack.len(),
+            last_ctx: self.last_ctx,
+            errors_len: self.errors.len(),
+            expected_tokens_len: self.expected_tokens.len(),
+            defer_invalid_node_creation: self.defer_invalid_node_creation,
+            tokens: self.tokens.checkpoint(),
+        }
+    }
+
+    /// Restores the parser to a previously captured [`Checkpoint`], discarding any progress
+    /// (tokens consumed, errors raised, `ctx`/`last_ctx` updated) made since. Without restoring
+    /// `last_ctx`, a caller that inspects it after a rewound speculative parse (as
+    /// `parse_with_items` does, once it falls back to reparsing a parenthesized group like
+    /// `with (a) as A:` as a single expression) could observe state left over from the abandoned
+    /// branch instead of what was true at the checkpoint. Likewise, `defer_invalid_node_creation` has to
+    /// be restored: `expect_and_recover` can set it while recovering from a malformed construct,
+    /// and if that happened inside a speculative branch that gets rewound, leaving it set would
+    /// make the top-level statement loop synthesize a bogus invalid-expression statement out of
+    /// the abandoned attempt's leftover range once parsing resumes.
+    fn rewind(&mut self, checkpoint: Checkpoint) {
+        let Checkpoint {
+            current,
+            last_token_end,
+            ctx,
+            ctx_stack_len,
+            last_ctx,
+            errors_len,
+            expected_tokens_len,
+            defer_invalid_node_creation,
+            tokens,
+        } = checkpoint;
+
+        self.current = current;
+        self.last_token_end = last_token_end;
+        self.ctx = ctx;
+        self.ctx_stack.truncate(ctx_stack_len);
+        self.last_ctx = last_ctx;
+        self.errors.truncate(errors_len);
+        self.expected_tokens.truncate(expected_tokens_len);
+        self.defer_invalid_node_creation = defer_invalid_node_creation;
+        self.tokens.rewind(tokens);
+    }
+
     /// Moves the parser to the next token. Returns the old current token as an owned value.
     fn next_token(&mut self) -> Spanned {
         let next = self
@@ -418,6 +556,10 @@ impl<'src> Parser<'src> {
             .next()
             .unwrap_or_else(|| (Tok::EndOfFile, TextRange::empty(self.source.text_len())));
 
+        // The parser actually advanced, so whatever tokens were probed against the previous
+        // current token are no longer relevant.
+        self.expected_tokens.clear();
+
         let current = std::mem::replace(&mut self.current, next);
 
         if !matches!(
@@ -474,6 +616,15 @@ impl<'src> Parser<'src> {
         true
     }
 
+    /// Records that `kind` was just probed for, so that a later failing [`Parser::expect`]
+    /// can report it as part of an "expected one of" message.
+    #[inline]
+    fn record_expected_token(&mut self, kind: TokenKind) {
+        if !self.expected_tokens.contains(&kind) {
+            self.expected_tokens.push(kind);
+        }
+    }
+
     /// Bumps the current token assuming it is of the given kind.
     ///
     /// # Panics
@@ -493,7 +644,16 @@ impl<'src> Parser<'src> {
         }
 
         let (found, range) = self.current_token();
-        self.add_error(ParseErrorType::ExpectedToken { found, expected }, range);
+        let error = match self.expected_tokens.as_slice() {
+            // Only the failing `expect` itself probed for a token, so fall back to the
+            // single-token message.
+            [] | [_] => ParseErrorType::ExpectedToken { found, expected },
+            _ => ParseErrorType::ExpectedOneOf {
+                found,
+                expected: self.expected_tokens.clone(),
+            },
+        };
+        self.add_error(error, range);
         false
     }
 
@@ -507,10 +667,25 @@ impl<'src> Parser<'src> {
             let range = self.skip_until(expected_set);
             self.defer_invalid_node_creation = Some(range);
 
-            self.add_error(
-                ParseErrorType::OtherError("unexpected tokens".into()),
-                range,
-            );
+            // A missing `:` (e.g. after a `match`/`case`/compound-statement header) is by far
+            // the most common reason this fails, and inserting it at the point we gave up
+            // recovering is always correct, so offer it as a machine-applicable fix.
+            if expected == TokenKind::Colon {
+                self.add_error_with_suggestion(
+                    ParseErrorType::OtherError("unexpected tokens".into()),
+                    range,
+                    ParseSuggestion {
+                        range: TextRange::empty(self.last_token_end),
+                        replacement: ":".to_string(),
+                        applicability: Applicability::MachineApplicable,
+                    },
+                );
+            } else {
+                self.add_error(
+                    ParseErrorType::OtherError("unexpected tokens".into()),
+                    range,
+                );
+            }
 
             self.eat(expected);
         }
@@ -520,6 +695,24 @@ impl<'src> Parser<'src> {
         self.errors.push(ParseError {
             error,
             location: range,
+            suggestion: None,
+            secondary_label: None,
+        });
+    }
+
+    /// Like [`Parser::add_error`], but attaches a machine-applicable (or otherwise automatable)
+    /// fix that a downstream consumer can use to resolve the error directly.
+    fn add_error_with_suggestion(
+        &mut self,
+        error: ParseErrorType,
+        range: TextRange,
+        suggestion: ParseSuggestion,
+    ) {
+        self.errors.push(ParseError {
+            error,
+            location: range,
+            suggestion: Some(suggestion),
+            secondary_label: None,
         });
     }
 
@@ -535,10 +728,14 @@ impl<'src> Parser<'src> {
     }
 
     fn at(&mut self, kind: TokenKind) -> bool {
+        self.record_expected_token(kind);
         self.current_kind() == kind
     }
 
     fn at_ts(&mut self, ts: TokenSet) -> bool {
+        for kind in ts.kinds() {
+            self.record_expected_token(kind);
+        }
         ts.contains(self.current_kind())
     }
 
@@ -584,6 +781,7 @@ impl<'src> Parser<'src> {
         allow_trailing_delim: bool,
         opening: TokenKind,
         delim: TokenKind,
+        recovery: SequenceRecovery,
         closing: TokenKind,
         mut func: impl FnMut(&mut Parser<'src>),
     ) -> TextRange {
@@ -593,6 +791,7 @@ impl<'src> Parser<'src> {
         self.parse_separated(
             allow_trailing_delim,
             delim,
+            recovery,
             [closing].as_slice(),
             |parser| {
                 func(parser);
@@ -612,12 +811,23 @@ impl<'src> Parser<'src> {
     /// encounter the tokens in `ending_set` it stops parsing when seeing the `EOF`
     /// or `Newline` token.
     ///
+    /// `recovery` controls what happens when an element is followed by something other than
+    /// `delim` or a token in `ending_set`: [`SequenceRecovery::Forbid`] stops the sequence right
+    /// there (the original, conservative behavior), while [`SequenceRecovery::InsertMissingDelim`]
+    /// assumes `delim` was simply forgotten and keeps parsing the rest of the sequence. The same
+    /// flag also governs whether two consecutive `delim`s (an empty slot, e.g. `(a,, b)`) are
+    /// reported and skipped rather than handed to `func`.
+    ///
+    /// Either way, every iteration of the loop either consumes at least one token or reports an
+    /// error and breaks, so malformed input can never make this loop spin forever.
+    ///
     /// Returns the last [`TextRange`] of the parsed elements. If none elements are
     /// parsed it returns `None`.
     fn parse_separated(
         &mut self,
         allow_trailing_delim: bool,
         delim: TokenKind,
+        recovery: SequenceRecovery,
         ending_set: impl Into<TokenSet>,
         mut func: impl FnMut(&mut Parser<'src>) -> TextRange,
     ) -> Option<TextRange> {
@@ -625,6 +835,16 @@ impl<'src> Parser<'src> {
         let mut final_range = None;
 
         while !self.at_ts(ending_set) {
+            if recovery == SequenceRecovery::InsertMissingDelim && self.at(delim) {
+                // Two consecutive delimiters: there's no element in between, so don't bother
+                // handing this slot to `func`.
+                let range = self.current_range();
+                self.add_error(ParseErrorType::OtherError("expected expression".into()), range);
+                self.eat(delim);
+                continue;
+            }
+
+            let element_start = self.current_range();
             final_range = Some(func(self));
 
             // exit the loop if a trailing `delim` is not allowed
@@ -636,8 +856,27 @@ impl<'src> Parser<'src> {
                 final_range = Some(self.current_range());
                 self.eat(delim);
             } else {
-                if self.at_expr() {
-                    self.expect(delim);
+                if recovery == SequenceRecovery::InsertMissingDelim
+                    && self.current_range() != element_start
+                    && self.at_expr()
+                {
+                    // We've already parsed one element and are looking at the start of
+                    // another, so the most likely explanation is a missing delimiter rather
+                    // than the sequence having ended; suggest inserting it. (If `func` didn't
+                    // consume anything, retrying it would never make progress, so stop instead.)
+                    let insertion_point = TextRange::empty(self.last_token_end);
+                    self.add_error_with_suggestion(
+                        ParseErrorType::ExpectedToken {
+                            found: self.current_kind(),
+                            expected: delim,
+                        },
+                        self.current_range(),
+                        ParseSuggestion {
+                            range: insertion_point,
+                            replacement: delim_text(delim).to_string(),
+                            applicability: Applicability::MachineApplicable,
+                        },
+                    );
                 } else {
                     break;
                 }
@@ -655,11 +894,18 @@ impl<'src> Parser<'src> {
     }
 
     fn handle_unexpected_indentation(&mut self, stmts: &mut Vec<Stmt>, error_msg: &str) {
-        self.bump(TokenKind::Indent);
+        let (_, indent_range) = self.bump(TokenKind::Indent);
 
-        self.add_error(
+        // Dedenting back to the enclosing block's indentation is the only sensible fix, and
+        // it's purely mechanical, so offer it as a machine-applicable suggestion.
+        self.add_error_with_suggestion(
             ParseErrorType::OtherError(error_msg.to_string()),
             self.current_range(),
+            ParseSuggestion {
+                range: indent_range,
+                replacement: String::new(),
+                applicability: Applicability::MachineApplicable,
+            },
         );
 
         while !self.at(TokenKind::Dedent) && !self.at(TokenKind::EndOfFile) {
@@ -682,11 +928,42 @@ impl<'src> Parser<'src> {
             TokenKind::While => Stmt::While(self.parse_while_stmt()),
             TokenKind::Def => Stmt::FunctionDef(self.parse_func_def_stmt(vec![], start_offset)),
             TokenKind::Class => Stmt::ClassDef(self.parse_class_def_stmt(vec![], start_offset)),
-            TokenKind::Match => Stmt::Match(self.parse_match_stmt()),
+            TokenKind::Match => self.parse_match_or_expr_stmt(),
             _ => self.parse_simple_stmt_newline(),
         }
     }
 
+    /// Dispatches on a leading `match` token. The soft-keyword lexer has already used a
+    /// heuristic (a top-level colon later in the logical line) to decide that this `match` is
+    /// being used as the match-statement keyword rather than an identifier, but that heuristic
+    /// can't see the whole grammar and can be fooled by expressions that happen to contain a
+    /// colon of their own. Speculatively parse it as a match statement, and if that doesn't
+    /// produce a sensible header, rewind and fall back to parsing `match` as an ordinary
+    /// identifier expression statement instead, so a bad guess doesn't cascade into unrelated
+    /// diagnostics.
+    fn parse_match_or_expr_stmt(&mut self) -> Stmt {
+        let checkpoint = self.checkpoint();
+        let errors_before = self.errors.len();
+
+        let match_stmt = self.parse_match_stmt();
+
+        // Only errors raised before the first `case` clause belong to the header; errors inside
+        // the case clauses don't make `match` any less likely to be the keyword.
+        let header_failed = match match_stmt.cases.first() {
+            Some(first_case) => self.errors[errors_before..]
+                .iter()
+                .any(|error| error.location.start() < first_case.range.start()),
+            None => self.errors.len() > errors_before,
+        };
+
+        if header_failed {
+            self.rewind(checkpoint);
+            return self.parse_simple_stmt_newline();
+        }
+
+        Stmt::Match(match_stmt)
+    }
+
     fn parse_match_stmt(&mut self) -> ast::StmtMatch {
         let start_offset = self.node_start();
 
@@ -794,6 +1071,7 @@ impl<'src> Parser<'src> {
     }
 
     fn parse_match_pattern_literal(&mut self) -> (Pattern, TextRange) {
+        let checkpoint = self.checkpoint();
         let (tok, range) = self.next_token();
         match tok {
             Tok::None => (
@@ -864,6 +1142,16 @@ impl<'src> Parser<'src> {
                     range,
                 });
                 let (parsed_expr, range) = self.parse_attr_expr_for_match_pattern(id, range);
+
+                // A trailing `[` means this wasn't a dotted-name value pattern after all, but a
+                // subscript expression (`obj.attr[0]:`), which isn't a legal pattern. (A trailing
+                // `(` is legitimate: it's how a dotted class pattern like `obj.Cls(x):` is
+                // written, and is handled by the caller's class-pattern upgrade.)
+                if self.at(TokenKind::Lsqb) {
+                    self.rewind(checkpoint);
+                    return self.parse_invalid_expr_match_pattern();
+                }
+
                 (
                     Pattern::MatchValue(ast::PatternMatchValue {
                         value: Box::new(parsed_expr.expr),
@@ -872,18 +1160,29 @@ impl<'src> Parser<'src> {
                     range,
                 )
             }
-            Tok::Name { name } => (
-                Pattern::MatchAs(ast::PatternMatchAs {
+            Tok::Name { name } => {
+                // A trailing `[` means this is a subscript expression (`d[0]:`), not a capture
+                // pattern, which isn't a legal pattern. (A trailing `(` is legitimate -- it's
+                // how a class pattern like `Point(x, y):` is written, and is handled by the
+                // caller's class-pattern upgrade.)
+                if self.at(TokenKind::Lsqb) {
+                    self.rewind(checkpoint);
+                    return self.parse_invalid_expr_match_pattern();
+                }
+
+                (
+                    Pattern::MatchAs(ast::PatternMatchAs {
+                        range,
+                        pattern: None,
+                        name: if name == "_" {
+                            None
+                        } else {
+                            Some(ast::Identifier { id: name, range })
+                        },
+                    }),
                     range,
-                    pattern: None,
-                    name: if name == "_" {
-                        None
-                    } else {
-                        Some(ast::Identifier { id: name, range })
-                    },
-                }),
-                range,
-            ),
+                )
+            }
             Tok::Minus
                 if matches!(
                     self.current_kind(),
@@ -907,27 +1206,85 @@ impl<'src> Parser<'src> {
                     range,
                 )
             }
-            kind => {
-                const RECOVERY_SET: TokenSet =
-                    TokenSet::new(&[TokenKind::Colon]).union(NEWLINE_EOF_SET);
-                self.add_error(
-                    ParseErrorType::InvalidMatchPatternLiteral {
-                        pattern: kind.into(),
-                    },
+            Tok::Plus
+                if matches!(
+                    self.current_kind(),
+                    TokenKind::Int | TokenKind::Float | TokenKind::Complex
+                ) =>
+            {
+                // Unlike `-`, a leading `+` is never valid in a match pattern literal (PEP 634
+                // only special-cases unary minus), but it's an easy typo to make when the rest
+                // of the pattern looks like ordinary Python arithmetic, so recover by dropping
+                // the `+` and parsing the number on its own.
+                self.add_error_with_suggestion(
+                    ParseErrorType::OtherError(
+                        "`+` is not allowed before a literal in a match pattern".to_string(),
+                    ),
                     range,
-                );
-                self.skip_until(RECOVERY_SET);
-                (
-                    Pattern::Invalid(ast::PatternMatchInvalid {
-                        value: self.src_text(range).into(),
+                    ParseSuggestion {
                         range,
-                    }),
-                    range.cover_offset(self.current_range().start()),
-                )
+                        replacement: String::new(),
+                        applicability: Applicability::MachineApplicable,
+                    },
+                );
+
+                self.parse_match_pattern_literal()
+            }
+            _ => {
+                self.rewind(checkpoint);
+                self.parse_invalid_expr_match_pattern()
             }
         }
     }
 
+    /// Fallback for match-pattern positions where the literal/name dispatch in
+    /// [`Parser::parse_match_pattern_literal`] can't form a valid pattern: a name or dotted name
+    /// followed by `[` (a subscript, `d[0]:`), or a pattern starting with a token that can't
+    /// begin a literal or name (e.g. `-x:`, `not x:`). Expressions that start with a valid
+    /// literal or name, such as `a * b:` or `a < b:`, are not routed here: the leading literal or
+    /// name becomes the pattern and the rest is left to the caller. Parses a full expression --
+    /// purely to get a clean span and skip past it -- then reports a single targeted error and
+    /// yields an invalid pattern so the caller can keep going. The expression stops at `:` and
+    /// `,`, so it never swallows the token that ends the case or separates sequence elements; a
+    /// `|` after it is parsed as a binary `|` and becomes part of the invalid pattern's span.
+    fn parse_invalid_expr_match_pattern(&mut self) -> (Pattern, TextRange) {
+        const RECOVERY_SET: TokenSet =
+            TokenSet::new(&[TokenKind::Colon, TokenKind::Comma, TokenKind::Vbar]).union(NEWLINE_EOF_SET);
+
+        if !self.at_expr() {
+            let range = self.current_range();
+            let pattern = self.current_kind();
+            self.add_error(ParseErrorType::InvalidMatchPatternLiteral { pattern }, range);
+            self.skip_until(RECOVERY_SET);
+            return (
+                Pattern::Invalid(ast::PatternMatchInvalid {
+                    value: self.src_text(range).into(),
+                    range,
+                }),
+                range.cover_offset(self.current_range().start()),
+            );
+        }
+
+        let (_, range) =
+            self.parse_expr_with_recovery(Parser::parse_expr2, RECOVERY_SET, "expecting expression");
+
+        self.add_error(
+            ParseErrorType::OtherError(format!(
+                "expressions are not allowed in match patterns, found `{}`",
+                self.src_text(range)
+            )),
+            range,
+        );
+
+        (
+            Pattern::Invalid(ast::PatternMatchInvalid {
+                value: self.src_text(range).into(),
+                range,
+            }),
+            range,
+        )
+    }
+
     fn parse_delimited_match_pattern(&mut self) -> (Pattern, TextRange) {
         let mut range = self.current_range();
 
@@ -944,12 +1301,15 @@ impl<'src> Parser<'src> {
 
         if matches!(self.current_kind(), TokenKind::Newline | TokenKind::Colon) {
             let range = self.current_range();
-            self.add_error(
-                ParseErrorType::OtherError(format!(
-                    "missing `{}`",
-                    if is_paren { ')' } else { ']' }
-                )),
+            let closing_char = if is_paren { ')' } else { ']' };
+            self.add_error_with_suggestion(
+                ParseErrorType::OtherError(format!("missing `{closing_char}`")),
                 range,
+                ParseSuggestion {
+                    range: TextRange::empty(self.last_token_end),
+                    replacement: closing_char.to_string(),
+                    applicability: Applicability::MachineApplicable,
+                },
             );
         }
 
@@ -996,7 +1356,12 @@ impl<'src> Parser<'src> {
         self.eat(TokenKind::Comma);
         let mut patterns = vec![first_elt];
 
-        let range = self.parse_separated(true, TokenKind::Comma, [ending].as_slice(), |parser| {
+        let range = self.parse_separated(
+            true,
+            TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
+            [ending].as_slice(),
+            |parser| {
             let (pattern, pattern_range) = parser.parse_match_pattern();
             patterns.push(pattern);
             pattern_range
@@ -1012,13 +1377,21 @@ impl<'src> Parser<'src> {
         )
     }
 
-    fn parse_match_pattern_lhs(&mut self) -> (Pattern, TextRange) {
-        let (mut lhs, mut range) = match self.current_kind() {
+    /// Parses a single match-pattern "leaf": a literal, a name, a star pattern, a mapping
+    /// pattern, or a parenthesized/bracketed sequence pattern. Unlike [`Parser::parse_match_pattern_lhs`],
+    /// this never upgrades the result to a class pattern, which makes it safe to use as the
+    /// target of a speculative class-pattern parse that might need to be rewound.
+    fn parse_match_pattern_lhs_leaf(&mut self) -> (Pattern, TextRange) {
+        match self.current_kind() {
             TokenKind::Lbrace => self.parse_match_pattern_mapping(),
             TokenKind::Star => self.parse_match_pattern_star(),
             TokenKind::Lpar | TokenKind::Lsqb => self.parse_delimited_match_pattern(),
             _ => self.parse_match_pattern_literal(),
-        };
+        }
+    }
+
+    fn parse_match_pattern_lhs(&mut self) -> (Pattern, TextRange) {
+        let (mut lhs, mut range) = self.parse_match_pattern_lhs_leaf();
 
         if self.at(TokenKind::Lpar) {
             (lhs, range) = self.parse_match_pattern_class(lhs, range);
@@ -1117,12 +1490,63 @@ impl<'src> Parser<'src> {
     }
 
     fn parse_match_pattern(&mut self) -> (Pattern, TextRange) {
+        // A leading `|` (e.g. `case | A | B:`) has no preceding alternative to attach to; it's
+        // a common typo, so drop it and parse the rest of the pattern as if it wasn't there.
+        if self.at(TokenKind::Vbar) {
+            let bar_range = self.current_range();
+            self.add_error_with_suggestion(
+                ParseErrorType::OtherError("leading `|` is not allowed in an or-pattern".to_string()),
+                bar_range,
+                ParseSuggestion {
+                    range: bar_range,
+                    replacement: String::new(),
+                    applicability: Applicability::MachineApplicable,
+                },
+            );
+            self.bump(TokenKind::Vbar);
+        }
+
         let (mut lhs, mut range) = self.parse_match_pattern_lhs();
 
         if self.at(TokenKind::Vbar) {
             let mut patterns = vec![lhs];
 
-            while self.eat(TokenKind::Vbar) {
+            while self.at(TokenKind::Vbar) {
+                let bar_range = self.current_range();
+                self.bump(TokenKind::Vbar);
+
+                // A doubled `|` (e.g. `case A || B:`) is almost always meant to be a single one.
+                if self.at(TokenKind::Vbar) {
+                    let second_bar_range = self.current_range();
+                    self.add_error_with_suggestion(
+                        ParseErrorType::OtherError(
+                            "use a single `|` in an or-pattern".to_string(),
+                        ),
+                        bar_range.cover(second_bar_range),
+                        ParseSuggestion {
+                            range: second_bar_range,
+                            replacement: String::new(),
+                            applicability: Applicability::MachineApplicable,
+                        },
+                    );
+                    self.bump(TokenKind::Vbar);
+                }
+
+                // A trailing `|` (e.g. `case A | B |:`) has no following alternative; stop here
+                // rather than trying (and failing) to parse one.
+                if matches!(
+                    self.current_kind(),
+                    TokenKind::Colon | TokenKind::Newline | TokenKind::As
+                ) {
+                    self.add_error(
+                        ParseErrorType::OtherError(
+                            "trailing `|` not allowed in an or-pattern".to_string(),
+                        ),
+                        bar_range,
+                    );
+                    break;
+                }
+
                 let (pattern, pattern_range) = self.parse_match_pattern_lhs();
                 range = range.cover(pattern_range);
                 patterns.push(pattern);
@@ -1188,6 +1612,7 @@ impl<'src> Parser<'src> {
             true,
             TokenKind::Lpar,
             TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
             TokenKind::Rpar,
             |parser| {
                 let (pattern, pattern_range) = parser.parse_match_pattern();
@@ -1261,15 +1686,35 @@ impl<'src> Parser<'src> {
                 value
             }
             _ => {
-                self.add_error(
-                    ParseErrorType::OtherError(format!(
-                        "`{}` invalid pattern match class",
-                        self.src_text(cls_range)
-                    )),
-                    cls_range,
-                );
+                let text = self.src_text(cls_range);
+                let error = ParseErrorType::OtherError(format!(
+                    "`{text}` invalid pattern match class"
+                ));
+
+                // The most common way to end up here is a string literal used where a dotted
+                // class name was expected, e.g. `case "Point"(x, y):`. In that case, stripping
+                // the quotes is likely (but not certain) to be what was intended.
+                let unquoted = text
+                    .strip_prefix('"')
+                    .or_else(|| text.strip_prefix('\''))
+                    .and_then(|rest| rest.strip_suffix('"').or_else(|| rest.strip_suffix('\'')))
+                    .filter(|inner| !inner.is_empty());
+
+                match unquoted {
+                    Some(inner) => self.add_error_with_suggestion(
+                        error,
+                        cls_range,
+                        ParseSuggestion {
+                            range: cls_range,
+                            replacement: inner.to_string(),
+                            applicability: Applicability::MaybeIncorrect,
+                        },
+                    ),
+                    None => self.add_error(error, cls_range),
+                }
+
                 Box::new(Expr::Invalid(ast::ExprInvalid {
-                    value: self.src_text(cls_range).into(),
+                    value: text.into(),
                     range: cls_range,
                 }))
             }
@@ -1299,12 +1744,28 @@ impl<'src> Parser<'src> {
             true,
             TokenKind::Lbrace,
             TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
             TokenKind::Rbrace,
             |parser| {
                 if parser.eat(TokenKind::DoubleStar) {
                     rest = Some(parser.parse_identifier());
                 } else {
+                    // A class pattern can never be a valid mapping-pattern key (PEP 634 only
+                    // allows literals, value patterns, and `_` there). Speculatively try the
+                    // usual class-pattern upgrade anyway -- it's the only way to know whether a
+                    // following `(...)` was meant to start the next key/value pair -- and if it
+                    // fires, rewind and keep only the leaf, leaving the `(` for the surrounding
+                    // comma-separated parse to flag as unexpected. Since the class-pattern
+                    // upgrade itself doesn't raise errors on the success path, rewinding here
+                    // leaves no spurious diagnostics behind.
+                    let checkpoint = parser.checkpoint();
                     let (pattern, pattern_range) = parser.parse_match_pattern_lhs();
+                    let (pattern, pattern_range) = if matches!(pattern, Pattern::MatchClass(_)) {
+                        parser.rewind(checkpoint);
+                        parser.parse_match_pattern_lhs_leaf()
+                    } else {
+                        (pattern, pattern_range)
+                    };
                     let key = match pattern {
                         Pattern::MatchValue(ast::PatternMatchValue { value, .. }) => *value,
                         Pattern::MatchSingleton(ast::PatternMatchSingleton { value, range }) => {
@@ -1390,11 +1851,7 @@ impl<'src> Parser<'src> {
         let while_start = self.node_start();
         self.bump(TokenKind::While);
 
-        let (test, _) = self.parse_expr_with_recovery(
-            Parser::parse_expr2,
-            [TokenKind::Colon].as_slice(),
-            "expecting expression after `while` keyword",
-        );
+        let (test, _) = self.parse_condition("while");
         self.expect_and_recover(TokenKind::Colon, TokenSet::EMPTY);
 
         let body = self.parse_body(Clause::While);
@@ -1486,12 +1943,23 @@ impl<'src> Parser<'src> {
             } else {
                 let (parsed_expr, expr_range) = self.parse_exprs();
                 if !parsed_expr.is_parenthesized && matches!(parsed_expr.expr, Expr::Tuple(_)) {
-                    self.add_error(
-                        ParseErrorType::OtherError(
-                            "multiple exception types must be parenthesized".to_string(),
-                        ),
-                        expr_range,
-                    );
+                    let recognized = self.recover_python2_syntax(Python2Construct::ExceptCommaName {
+                        tuple: &parsed_expr.expr,
+                        range: expr_range,
+                    });
+                    if !recognized {
+                        self.add_error_with_suggestion(
+                            ParseErrorType::OtherError(
+                                "multiple exception types must be parenthesized".to_string(),
+                            ),
+                            expr_range,
+                            ParseSuggestion {
+                                range: expr_range,
+                                replacement: format!("({})", self.src_text(expr_range)),
+                                applicability: Applicability::MachineApplicable,
+                            },
+                        );
+                    }
                 }
                 Some(Box::new(parsed_expr.expr))
             };
@@ -1640,11 +2108,16 @@ impl<'src> Parser<'src> {
         let returns = if self.eat(TokenKind::Rarrow) {
             let (returns, range) = self.parse_exprs();
             if !returns.is_parenthesized && matches!(returns.expr, Expr::Tuple(_)) {
-                self.add_error(
+                self.add_error_with_suggestion(
                     ParseErrorType::OtherError(
                         "multiple return types must be parenthesized".to_string(),
                     ),
                     range,
+                    ParseSuggestion {
+                        range,
+                        replacement: format!("({})", self.src_text(range)),
+                        applicability: Applicability::MachineApplicable,
+                    },
                 );
             }
             Some(Box::new(returns.expr))
@@ -1716,11 +2189,16 @@ impl<'src> Parser<'src> {
                 );
             }
             Expr::NamedExpr(_) if !context_expr.is_parenthesized => {
-                self.add_error(
+                self.add_error_with_suggestion(
                     ParseErrorType::OtherError(
                         "unparenthesized named expression not allowed".into(),
                     ),
                     range,
+                    ParseSuggestion {
+                        range,
+                        replacement: format!("({})", self.src_text(range)),
+                        applicability: Applicability::MachineApplicable,
+                    },
                 );
             }
             _ => {}
@@ -1770,106 +2248,77 @@ impl<'src> Parser<'src> {
 
         // Consider the two `WithItem` examples below:
         //      1) `(a) as A`
-        //      2) `(a)`
+        //      2) `(a, b)`
         //
-        // In the first example, the `item` contains a parenthesized expression,
-        // while the second example is a parenthesized `WithItem`. This situation
-        // introduces ambiguity during parsing. When encountering an opening parenthesis
-        // `(,` the parser may initially assume it's parsing a parenthesized `WithItem`.
-        // However, this assumption doesn't hold for the first case, `(a) as A`, where
-        // `(a)` represents a parenthesized expression.
-        //
-        // To disambiguate, the following heuristic was created. First, assume we're
-        // parsing an expression, then we look for the following tokens:
-        //      i) `as` keyword outside parenthesis
-        //      ii) `,` outside or inside parenthesis
-        //      iii) `:=` inside an 1-level nested parenthesis
-        //      iv) `*` inside an 1-level nested parenthesis, representing a starred
-        //         expression
-        //
-        // If we find case i we treat it as in case 1. For case ii, we only treat it as in
-        // case 1 if the comma is outside of parenthesis and we've seen an `Rpar` or `Lpar`
-        // before the comma.
-        // Cases iii and iv are special cases, when we find them, we treat it as in case 2.
-        // The reason for this is that the resulting AST node needs to be a tuple for cases
-        // iii and iv instead of multiple `WithItem`s. For example, `with (a, b := 0, c): ...`
-        // will be parsed as one `WithItem` containing a tuple, instead of three different `WithItem`s.
-        let mut treat_it_as_expr = true;
-        if has_seen_lpar {
-            let mut index = 1;
-            let mut paren_nesting = 1;
-            let mut ignore_comma_check = false;
-            let mut has_seen_rpar = false;
-            let mut has_seen_colon_equal = false;
-            let mut has_seen_star = false;
-            let mut prev_token = self.current_kind();
-            loop {
-                let (kind, _) = self.peek_nth(index);
-                match kind {
-                    TokenKind::Lpar => {
-                        paren_nesting += 1;
-                    }
-                    TokenKind::Rpar => {
-                        paren_nesting -= 1;
-                        has_seen_rpar = true;
-                    }
-                    // Check for `:=` inside an 1-level nested parens, e.g. `with (a, b := c): ...`
-                    TokenKind::ColonEqual if paren_nesting == 1 => {
-                        treat_it_as_expr = true;
-                        ignore_comma_check = true;
-                        has_seen_colon_equal = true;
-                    }
-                    // Check for starred expressions inside an 1-level nested parens,
-                    // e.g. `with (a, *b): ...`
-                    TokenKind::Star if paren_nesting == 1 && !LITERAL_SET.contains(prev_token) => {
-                        treat_it_as_expr = true;
-                        ignore_comma_check = true;
-                        has_seen_star = true;
-                    }
-                    // Check for `as` keyword outside parens
-                    TokenKind::As => {
-                        treat_it_as_expr = paren_nesting == 0;
-                        ignore_comma_check = true;
-                    }
-                    TokenKind::Comma if !ignore_comma_check => {
-                        // If the comma is outside of parens, treat it as an expression
-                        // if we've seen `(` and `)`.
-                        if paren_nesting == 0 {
-                            treat_it_as_expr = has_seen_lpar && has_seen_rpar;
-                        } else if !has_seen_star && !has_seen_colon_equal {
-                            treat_it_as_expr = false;
-                        }
-                    }
-                    TokenKind::Colon | TokenKind::Newline => break,
-                    _ => {}
-                }
+        // In the first example, the parenthesized group is a single parenthesized expression,
+        // while in the second it's a parenthesized list of `WithItem`s. Telling the two apart
+        // requires looking past the matching `)`, which used to be done with a hand-rolled
+        // token lookahead. Instead, take a checkpoint and speculatively parse the group as a
+        // parenthesized, comma-separated list of `WithItem`s. If that doesn't cleanly consume
+        // up to the closing `)` without raising any errors, rewind and fall back to parsing the
+        // group as a single expression, e.g. for `(a) as A`, or for a parenthesized tuple
+        // containing a walrus or starred element -- both of which must stay a single `WithItem`
+        // whose context expression is a tuple, e.g. `with (a, b := 0, c): ...`.
+        let treat_it_as_expr = if has_seen_lpar {
+            let checkpoint = self.checkpoint();
+            let errors_before = self.errors.len();
+
+            self.bump(TokenKind::Lpar);
+            self.parse_separated(
+                true,
+                TokenKind::Comma,
+                SequenceRecovery::Forbid,
+                [TokenKind::Rpar].as_slice(),
+                |parser| {
+                    let item = parser.parse_with_item();
+                    let range = item.range;
+                    items.push(item);
+                    range
+                },
+            );
 
-                index += 1;
-                prev_token = kind;
+            if self.errors.len() == errors_before
+                && self.at(TokenKind::Rpar)
+                && matches!(self.peek_nth(1).0, TokenKind::Colon | TokenKind::Newline)
+            {
+                // Only treat the group as a parenthesized `WithItem` list if nothing but `:` or
+                // a newline follows the closing `)`. Anything else -- most importantly `as`, as
+                // in `with (a) as A:` -- means the parenthesized group was actually a single
+                // expression whose `as`-binding lives outside the parens.
+                false
+            } else {
+                items.clear();
+                self.rewind(checkpoint);
+                true
             }
-        }
-
-        if !treat_it_as_expr && has_seen_lpar {
-            self.eat(TokenKind::Lpar);
-        }
+        } else {
+            true
+        };
 
         let ending = if has_seen_lpar && treat_it_as_expr {
             [TokenKind::Colon]
         } else {
             [TokenKind::Rpar]
         };
-        self.parse_separated(
-            // Only allow a trailing delimiter if we've seen a `(`.
-            has_seen_lpar,
-            TokenKind::Comma,
-            ending.as_slice(),
-            |parser| {
-                let item = parser.parse_with_item();
-                let range = item.range;
-                items.push(item);
-                range
-            },
-        );
+
+        // If the speculative parenthesized-list parse above already succeeded, `items` is
+        // populated and the closing `)` hasn't been consumed yet; only the no-parens and
+        // single-expression cases still need to be parsed here.
+        if !has_seen_lpar || treat_it_as_expr {
+            self.parse_separated(
+                // Only allow a trailing delimiter if we've seen a `(`.
+                has_seen_lpar,
+                TokenKind::Comma,
+                SequenceRecovery::Forbid,
+                ending.as_slice(),
+                |parser| {
+                    let item = parser.parse_with_item();
+                    let range = item.range;
+                    items.push(item);
+                    range
+                },
+            );
+        }
         // Special-case: if we have a parenthesized `WithItem` that was parsed as
         // an expression, then the item should _exclude_ the outer parentheses in
         // its range. For example:
@@ -1969,9 +2418,15 @@ impl<'src> Parser<'src> {
         let (annotation, _) = self.parse_exprs();
 
         if matches!(annotation.expr, Expr::Tuple(_)) && !annotation.is_parenthesized {
-            self.add_error(
+            let range = annotation.expr.range();
+            self.add_error_with_suggestion(
                 ParseErrorType::OtherError("annotation cannot be unparenthesized".into()),
-                annotation.expr.range(),
+                range,
+                ParseSuggestion {
+                    range,
+                    replacement: format!("({})", self.src_text(range)),
+                    applicability: Applicability::MachineApplicable,
+                },
             );
         }
 
@@ -2018,20 +2473,104 @@ impl<'src> Parser<'src> {
         }
     }
 
-    fn parse_simple_stmt_newline(&mut self) -> Stmt {
-        let stmt = self.parse_simple_stmt();
-
-        self.last_ctx = ParserCtxFlags::empty();
-        let has_eaten_semicolon = self.eat(TokenKind::Semi);
-        let has_eaten_newline = self.eat(TokenKind::Newline);
+    /// Recognizes a Python 2 idiom that the grammar no longer accepts and, if found, emits a
+    /// dedicated diagnostic with a fix suggestion in place of the generic error the caller would
+    /// otherwise report. Returns `true` if an idiom was recognized and reported.
+    ///
+    /// New idioms (e.g. the `<>` inequality operator) should be added as additional
+    /// [`Python2Construct`] variants here, rather than growing bespoke detection in the
+    /// individual statement parsers.
+    fn recover_python2_syntax(&mut self, construct: Python2Construct) -> bool {
+        match construct {
+            Python2Construct::ExceptCommaName { tuple, range } => {
+                let Expr::Tuple(ast::ExprTuple { elts, .. }) = tuple else {
+                    return false;
+                };
+                let [exc_type, Expr::Name(name)] = elts.as_slice() else {
+                    return false;
+                };
+                if !self.at(TokenKind::Colon) {
+                    return false;
+                }
 
-        if !has_eaten_newline && !has_eaten_semicolon && self.at_simple_stmt() {
-            let range = self.current_range();
-            self.add_error(
-                ParseErrorType::SimpleStmtsInSameLine,
-                stmt.range().cover(range),
-            );
-        }
+                self.add_error_with_suggestion(
+                    ParseErrorType::OtherError(
+                        "old-style exception binding; use `as` instead of a comma".to_string(),
+                    ),
+                    range,
+                    ParseSuggestion {
+                        range,
+                        replacement: format!(
+                            "{} as {}",
+                            self.src_text(exc_type.range()),
+                            name.id
+                        ),
+                        applicability: Applicability::MachineApplicable,
+                    },
+                );
+
+                true
+            }
+            Python2Construct::PrintOrExecStatement { stmt } => {
+                let Stmt::Expr(ast::StmtExpr { value, .. }) = stmt else {
+                    return false;
+                };
+                let Expr::Name(ast::ExprName { id, range: name_range, .. }) = value.as_ref() else {
+                    return false;
+                };
+                if !matches!(id.as_str(), "print" | "exec") {
+                    return false;
+                }
+
+                let operand_start = self.current_range().start();
+                let mut operand_end = operand_start;
+                let mut offset = 0;
+                loop {
+                    let (kind, range) = self.peek_nth(offset);
+                    if matches!(kind, TokenKind::Newline | TokenKind::Semi | TokenKind::EndOfFile) {
+                        break;
+                    }
+                    operand_end = range.end();
+                    offset += 1;
+                }
+                let operand_range = TextRange::new(operand_start, operand_end);
+
+                self.add_error_with_suggestion(
+                    ParseErrorType::OtherError(format!(
+                        "`{id}` is a function in Python 3; did you mean `{id}(...)`?"
+                    )),
+                    name_range.cover(operand_range),
+                    ParseSuggestion {
+                        range: operand_range,
+                        replacement: format!("({})", self.src_text(operand_range)),
+                        applicability: Applicability::MaybeIncorrect,
+                    },
+                );
+
+                true
+            }
+        }
+    }
+
+    fn parse_simple_stmt_newline(&mut self) -> Stmt {
+        let stmt = self.parse_simple_stmt();
+
+        self.last_ctx = ParserCtxFlags::empty();
+        let has_eaten_semicolon = self.eat(TokenKind::Semi);
+        let has_eaten_newline = self.eat(TokenKind::Newline);
+
+        if !has_eaten_newline && !has_eaten_semicolon && self.at_simple_stmt() {
+            let recognized = self.recover_python2_syntax(Python2Construct::PrintOrExecStatement {
+                stmt: &stmt,
+            });
+            if !recognized {
+                let range = self.current_range();
+                self.add_error(
+                    ParseErrorType::SimpleStmtsInSameLine,
+                    stmt.range().cover(range),
+                );
+            }
+        }
 
         if !has_eaten_newline && self.at_compound_stmt() {
             // Avoid create `SimpleStmtAndCompoundStmtInSameLine` error when the
@@ -2188,6 +2727,7 @@ impl<'src> Parser<'src> {
         self.parse_separated(
             true,
             TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
             [TokenKind::Newline].as_slice(),
             |parser| {
                 let (mut target, target_range) = parser.parse_expr();
@@ -2195,12 +2735,17 @@ impl<'src> Parser<'src> {
 
                 if matches!(target.expr, Expr::BoolOp(_) | Expr::Compare(_)) {
                     // Should we make `target` an `Expr::Invalid` here?
-                    parser.add_error(
+                    parser.add_error_with_suggestion(
                         ParseErrorType::OtherError(format!(
                             "`{}` not allowed in `del` statement",
                             parser.src_text(target_range)
                         )),
                         target_range,
+                        ParseSuggestion {
+                            range: target_range,
+                            replacement: String::new(),
+                            applicability: Applicability::MaybeIncorrect,
+                        },
                     );
                 }
                 targets.push(target.expr);
@@ -2218,7 +2763,39 @@ impl<'src> Parser<'src> {
         let start = self.node_start();
         self.bump(TokenKind::Assert);
 
-        let (test, _) = self.parse_expr();
+        let (mut test, mut test_range) = self.parse_expr();
+
+        // As in `parse_condition`, recover from the common mistake of writing `=` instead of
+        // `==`, e.g. `assert x = 5`.
+        if self.at(TokenKind::Equal) {
+            let eq_range = self.current_range();
+            self.bump(TokenKind::Equal);
+
+            self.add_error_with_suggestion(
+                ParseErrorType::AssignmentInCondition,
+                eq_range,
+                ParseSuggestion {
+                    range: eq_range,
+                    replacement: "==".to_string(),
+                    applicability: Applicability::MachineApplicable,
+                },
+            );
+
+            let (rhs, rhs_range) = self.parse_expr_with_recovery(
+                Parser::parse_expr2,
+                [TokenKind::Comma, TokenKind::Newline].as_slice(),
+                "expecting expression after `=` in `assert` statement",
+            );
+
+            test_range = test_range.cover(rhs_range);
+            test = Expr::Compare(ast::ExprCompare {
+                left: Box::new(test.expr),
+                ops: vec![CmpOp::Eq],
+                comparators: vec![rhs.expr],
+                range: test_range,
+            })
+            .into();
+        }
 
         let msg = if self.eat(TokenKind::Comma) {
             let (msg, _) = self.parse_expr();
@@ -2243,6 +2820,7 @@ impl<'src> Parser<'src> {
         self.parse_separated(
             false,
             TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
             [TokenKind::Newline].as_slice(),
             |parser| {
                 let ident = parser.parse_identifier();
@@ -2267,6 +2845,7 @@ impl<'src> Parser<'src> {
         self.parse_separated(
             false,
             TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
             [TokenKind::Newline].as_slice(),
             |parser| {
                 let ident = parser.parse_identifier();
@@ -2310,11 +2889,16 @@ impl<'src> Parser<'src> {
 
             if let Expr::Tuple(node) = &exc.expr {
                 if !exc.is_parenthesized {
-                    self.add_error(
+                    self.add_error_with_suggestion(
                         ParseErrorType::OtherError(
                             "unparenthesized tuple not allowed in `raise` statement".to_string(),
                         ),
                         node.range,
+                        ParseSuggestion {
+                            range: node.range,
+                            replacement: format!("({})", self.src_text(node.range)),
+                            applicability: Applicability::MachineApplicable,
+                        },
                     );
                 }
             }
@@ -2393,6 +2977,7 @@ impl<'src> Parser<'src> {
             true,
             TokenKind::Lsqb,
             TokenKind::Comma,
+            SequenceRecovery::Forbid,
             TokenKind::Rsqb,
             |parser| {
                 type_params.push(parser.parse_type_param());
@@ -2489,6 +3074,7 @@ impl<'src> Parser<'src> {
         self.parse_separated(
             false,
             TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
             [TokenKind::Newline].as_slice(),
             |parser| {
                 let alias = parser.parse_alias();
@@ -2528,9 +3114,14 @@ impl<'src> Parser<'src> {
 
         if level == 0 && module.is_none() {
             let range = self.current_range();
-            self.add_error(
+            self.add_error_with_suggestion(
                 ParseErrorType::OtherError("missing module name".to_string()),
                 range,
+                ParseSuggestion {
+                    range: TextRange::empty(self.last_token_end),
+                    replacement: "module_name".to_string(),
+                    applicability: Applicability::HasPlaceholders,
+                },
             );
         }
 
@@ -2542,6 +3133,7 @@ impl<'src> Parser<'src> {
                 true,
                 TokenKind::Lpar,
                 TokenKind::Comma,
+                SequenceRecovery::InsertMissingDelim,
                 TokenKind::Rpar,
                 |parser| {
                     names.push(parser.parse_alias());
@@ -2551,6 +3143,7 @@ impl<'src> Parser<'src> {
             self.parse_separated(
                 false,
                 TokenKind::Comma,
+                SequenceRecovery::InsertMissingDelim,
                 [TokenKind::Newline].as_slice(),
                 |parser| {
                     let alias = parser.parse_alias();
@@ -2574,11 +3167,7 @@ impl<'src> Parser<'src> {
         let if_start = self.node_start();
         self.bump(TokenKind::If);
 
-        let (test, _) = self.parse_expr_with_recovery(
-            Parser::parse_expr2,
-            [TokenKind::Colon].as_slice(),
-            "expecting expression after `if` keyword",
-        );
+        let (test, _) = self.parse_condition("if");
         self.expect_and_recover(TokenKind::Colon, TokenSet::EMPTY);
 
         let body = self.parse_body(Clause::If);
@@ -2604,11 +3193,7 @@ impl<'src> Parser<'src> {
             let elif_start = self.node_start();
             self.bump(TokenKind::Elif);
 
-            let (test, _) = self.parse_expr_with_recovery(
-                Parser::parse_expr2,
-                [TokenKind::Colon].as_slice(),
-                "expecting expression after `elif` keyword",
-            );
+            let (test, _) = self.parse_condition("elif");
             self.expect_and_recover(TokenKind::Colon, TokenSet::EMPTY);
 
             let body = self.parse_body(Clause::ElIf);
@@ -2637,14 +3222,60 @@ impl<'src> Parser<'src> {
         elif_else_stmts
     }
 
+    /// Parses a clause's body: either a simple-statement suite on the same line (`if x: pass`)
+    /// or an indented block starting on the next line. Ordinarily the next token tells these
+    /// apart unambiguously, but malformed input can make `at_simple_stmt()` look true for what
+    /// was actually meant to be an indented block (e.g. a statement keyword typo'd onto the
+    /// header's line). Rather than committing to the simple-statement interpretation the moment
+    /// it looks applicable, take a checkpoint and speculatively parse it that way first; if that
+    /// raises any errors, rewind and also try the indented-block interpretation, then keep
+    /// whichever produced fewer errors.
     fn parse_body(&mut self, parent_clause: Clause) -> Vec<Stmt> {
-        let mut stmts = vec![];
+        let checkpoint = self.checkpoint();
+        let errors_before = self.errors.len();
 
-        // Check if we are currently at a simple statement
-        if !self.eat(TokenKind::Newline) && self.at_simple_stmt() {
-            return self.parse_simple_stmts();
+        let ate_newline = self.eat(TokenKind::Newline);
+        if !ate_newline && self.at_simple_stmt() {
+            let stmts = self.parse_simple_stmts();
+            let simple_stmt_errors = self.errors.split_off(errors_before);
+
+            if simple_stmt_errors.is_empty() {
+                return stmts;
+            }
+
+            // The simple-statement interpretation wasn't clean; remember where it left off, then
+            // rewind and see whether this was actually meant to be an indented block instead.
+            let after_simple_stmt = self.checkpoint();
+            self.rewind(checkpoint);
+
+            let block_stmts = self.parse_indented_block(parent_clause);
+            let block_stmt_errors = self.errors.len() - errors_before;
+
+            return if block_stmt_errors < simple_stmt_errors.len() {
+                block_stmts
+            } else {
+                // The indented-block interpretation didn't do any better (or did worse); go back
+                // to the simple-statement result and restore the errors it raised.
+                self.rewind(after_simple_stmt);
+                self.errors.extend(simple_stmt_errors);
+                stmts
+            };
         }
 
+        self.parse_indented_block(parent_clause)
+    }
+
+    /// Parses a clause's body as an indented block (`NEWLINE INDENT stmt+ DEDENT`), raising an
+    /// error if no indented block is found. Split out of [`Parser::parse_body`] so that function
+    /// can speculatively try this interpretation after a simple-statement attempt fails. Eats a
+    /// leading `Newline` itself (a no-op if the caller already consumed it) so it behaves the
+    /// same whether it's reached directly or after [`Parser::parse_body`] rewinds to a checkpoint
+    /// taken before that `Newline` was eaten.
+    fn parse_indented_block(&mut self, parent_clause: Clause) -> Vec<Stmt> {
+        self.eat(TokenKind::Newline);
+
+        let mut stmts = vec![];
+
         if self.eat(TokenKind::Indent) {
             const BODY_END_SET: TokenSet =
                 TokenSet::new(&[TokenKind::Dedent]).union(NEWLINE_EOF_SET);
@@ -2674,29 +3305,49 @@ impl<'src> Parser<'src> {
         stmts
     }
 
-    /// Parses every Python expression.
-    fn parse_exprs(&mut self) -> (ParsedExpr, TextRange) {
-        let (parsed_expr, expr_range) = self.parse_expr();
+    /// Parses a single expression, folding in whichever of the optional trailing forms --
+    /// conditional expression (`a if b else c`), named expression (`a := b`), and unparenthesized
+    /// tuple (`a, b`) -- `restrictions` allows. This is the one place that threads the `expr_bp`
+    /// core through those optional forms; [`Parser::parse_exprs`], [`Parser::parse_expr`],
+    /// [`Parser::parse_expr2`], and [`Parser::parse_expr_simple`] are thin wrappers over fixed
+    /// [`Restrictions`] sets, kept around because most call sites read better naming the
+    /// expression shape they want than spelling out the flags.
+    ///
+    /// Note that the `for`-target restriction tracked by `ParserCtxFlags::FOR_TARGET` is handled
+    /// separately, via `self.ctx`, rather than as a `Restrictions` flag: it's set for the whole
+    /// duration of parsing a `for` loop's target (potentially many nested `expr_bp` calls deep),
+    /// not just for the one trailing form a single `parse_expr_restricted` call resolves.
+    fn parse_expr_restricted(&mut self, restrictions: Restrictions) -> ExprWithRange {
+        let (mut parsed_expr, mut expr_range) = self.expr_bp(1);
 
-        if self.at(TokenKind::Comma) {
-            return self.parse_tuple_expr(parsed_expr.expr, expr_range, Parser::parse_expr);
+        if restrictions.contains(Restrictions::ALLOW_COND_EXPR) && self.at(TokenKind::If) {
+            (parsed_expr, expr_range) = self.parse_if_expr(parsed_expr.expr, expr_range);
+        }
+
+        if restrictions.contains(Restrictions::ALLOW_NAMED_EXPR) && self.at(TokenKind::ColonEqual)
+        {
+            (parsed_expr, expr_range) = self.parse_named_expr(parsed_expr.expr, expr_range);
+        }
+
+        if restrictions.contains(Restrictions::ALLOW_TUPLE) && self.at(TokenKind::Comma) {
+            (parsed_expr, expr_range) =
+                self.parse_tuple_expr(parsed_expr.expr, expr_range, Parser::parse_expr);
         }
 
         (parsed_expr, expr_range)
     }
 
+    /// Parses every Python expression.
+    fn parse_exprs(&mut self) -> ExprWithRange {
+        self.parse_expr_restricted(Restrictions::ALLOW_COND_EXPR | Restrictions::ALLOW_TUPLE)
+    }
+
     /// Parses every Python expression except unparenthesized tuple and named expressions.
     ///
     /// NOTE: If you have expressions separated by commas and want to parse them individually,
     /// instead of a tuple, use this function!
     fn parse_expr(&mut self) -> ExprWithRange {
-        let (parsed_expr, expr_range) = self.parse_expr_simple();
-
-        if self.at(TokenKind::If) {
-            return self.parse_if_expr(parsed_expr.expr, expr_range);
-        }
-
-        (parsed_expr, expr_range)
+        self.parse_expr_restricted(Restrictions::ALLOW_COND_EXPR)
     }
 
     /// Parses every Python expression except unparenthesized tuple.
@@ -2704,18 +3355,12 @@ impl<'src> Parser<'src> {
     /// NOTE: If you have expressions separated by commas and want to parse them individually,
     /// instead of a tuple, use this function!
     fn parse_expr2(&mut self) -> ExprWithRange {
-        let (parsed_expr, expr_range) = self.parse_expr();
-
-        if self.at(TokenKind::ColonEqual) {
-            return self.parse_named_expr(parsed_expr.expr, expr_range);
-        }
-
-        (parsed_expr, expr_range)
+        self.parse_expr_restricted(Restrictions::ALLOW_COND_EXPR | Restrictions::ALLOW_NAMED_EXPR)
     }
 
     /// Parses every Python expression except unparenthesized tuple and `if` expression.
     fn parse_expr_simple(&mut self) -> ExprWithRange {
-        self.expr_bp(1)
+        self.parse_expr_restricted(Restrictions::empty())
     }
 
     /// Tries to parse an expression (using `parse_func`), and recovers from
@@ -2747,6 +3392,100 @@ impl<'src> Parser<'src> {
         }
     }
 
+    /// Parses the boolean condition of an `if`/`elif`/`while` statement, recovering from the
+    /// common mistake (especially among users coming from C-like languages) of writing `=`
+    /// instead of `==`. The `=` is treated as if `==` had been written, so the rest of the file
+    /// still parses as a valid comparison.
+    fn parse_condition(&mut self, keyword: &str) -> ExprWithRange {
+        let (mut test, mut test_range) = self.parse_expr_with_recovery(
+            Parser::parse_expr2,
+            [TokenKind::Equal, TokenKind::Colon].as_slice(),
+            format_args!("expecting expression after `{keyword}` keyword"),
+        );
+
+        if self.at(TokenKind::Equal) {
+            let eq_range = self.current_range();
+            self.bump(TokenKind::Equal);
+
+            self.add_error_with_suggestion(
+                ParseErrorType::AssignmentInCondition,
+                eq_range,
+                ParseSuggestion {
+                    range: eq_range,
+                    replacement: "==".to_string(),
+                    applicability: Applicability::MachineApplicable,
+                },
+            );
+
+            let (rhs, rhs_range) = self.parse_expr_with_recovery(
+                Parser::parse_expr2,
+                [TokenKind::Colon].as_slice(),
+                format_args!("expecting expression after `=` in `{keyword}` condition"),
+            );
+
+            test_range = test_range.cover(rhs_range);
+            test = Expr::Compare(ast::ExprCompare {
+                left: Box::new(test.expr),
+                ops: vec![CmpOp::Eq],
+                comparators: vec![rhs.expr],
+                range: test_range,
+            })
+            .into();
+        }
+
+        (test, test_range)
+    }
+
+    /// Detects a C/JavaScript-style `&&`/`||`, which the lexer sees as two adjacent
+    /// `&`/`|` tokens (Python spells these `and`/`or`). Returns the boolean operator it
+    /// corresponds to and the binding power to use, mirroring the entries in [`Parser::current_op`].
+    fn current_c_style_bool_op(&mut self) -> Option<(BoolOp, u8)> {
+        let kind = self.current_kind();
+        let op = match kind {
+            TokenKind::Amper => BoolOp::And,
+            TokenKind::Vbar => BoolOp::Or,
+            _ => return None,
+        };
+
+        let first_range = self.current_range();
+        let (second_kind, second_range) = self.peek_nth(1);
+        if second_kind != kind || second_range.start() != first_range.end() {
+            return None;
+        }
+
+        let op_bp = match op {
+            BoolOp::And => 5,
+            BoolOp::Or => 4,
+        };
+        Some((op, op_bp))
+    }
+
+    /// Detects and consumes a trailing C-style `++`/`--`, e.g. `x++`. Unlike `x + +y`/`x - -y`,
+    /// which are legitimate double-unary expressions, `x++` isn't followed by anything that
+    /// could itself start an expression, so that's what distinguishes the two.
+    fn eat_c_style_incr_decr(&mut self) -> Option<(&'static str, TextRange)> {
+        let kind = self.current_kind();
+        if !matches!(kind, TokenKind::Plus | TokenKind::Minus) {
+            return None;
+        }
+
+        let first_range = self.current_range();
+        let (second_kind, second_range) = self.peek_nth(1);
+        if second_kind != kind || second_range.start() != first_range.end() {
+            return None;
+        }
+
+        if EXPR_SET.contains(self.peek_nth(2).0) {
+            return None;
+        }
+
+        self.next_token();
+        self.next_token();
+
+        let op_text = if kind == TokenKind::Plus { "++" } else { "--" };
+        Some((op_text, first_range.cover(second_range)))
+    }
+
     /// Binding powers of operators for a Pratt parser.
     ///
     /// See <https://matklad.github.io/2020/04/13/simple-but-powerful-pratt-parsing.html>
@@ -2787,9 +3526,74 @@ impl<'src> Parser<'src> {
     /// Uses the Pratt parser algorithm.
     /// See <https://matklad.github.io/2020/04/13/simple-but-powerful-pratt-parsing.html>
     fn expr_bp(&mut self, bp: u8) -> ExprWithRange {
+        self.recursion_depth += 1;
+        if self.recursion_depth > MAX_EXPRESSION_NESTING {
+            self.recursion_depth -= 1;
+            let range = self.current_range();
+            self.add_error(ParseErrorType::ExpressionTooDeeplyNested, range);
+            return (
+                Expr::Invalid(ast::ExprInvalid {
+                    value: String::new().into(),
+                    range,
+                })
+                .into(),
+                range,
+            );
+        }
+
         let (mut lhs, mut lhs_range) = self.parse_lhs();
 
         loop {
+            if let Some((op_text, range)) = self.eat_c_style_incr_decr() {
+                // There's no sensible binary expression to build here, so just drop the
+                // offending `++`/`--` and keep going with `lhs` unchanged.
+                let keyword = if op_text == "++" { "+= 1" } else { "-= 1" };
+                self.add_error(
+                    ParseErrorType::OtherError(format!(
+                        "`{op_text}` is not a valid operator in Python; use `{keyword}` instead"
+                    )),
+                    range,
+                );
+                lhs_range = lhs_range.cover(range);
+                continue;
+            }
+
+            if let Some((bool_op, op_bp)) = self.current_c_style_bool_op() {
+                if op_bp < bp {
+                    break;
+                }
+
+                let range = self.current_range().cover(self.peek_nth(1).1);
+                self.next_token();
+                self.next_token();
+
+                let (op_text, keyword) = match bool_op {
+                    BoolOp::And => ("&&", "and"),
+                    BoolOp::Or => ("||", "or"),
+                };
+                self.add_error_with_suggestion(
+                    ParseErrorType::OtherError(format!(
+                        "`{op_text}` is not a valid operator in Python; use `{keyword}` instead"
+                    )),
+                    range,
+                    ParseSuggestion {
+                        range,
+                        replacement: keyword.to_string(),
+                        applicability: Applicability::MachineApplicable,
+                    },
+                );
+
+                let (rhs, rhs_range) = self.expr_bp(op_bp + 1);
+                lhs_range = lhs_range.cover(rhs_range);
+                lhs.expr = Expr::BoolOp(ast::ExprBoolOp {
+                    values: vec![lhs.expr, rhs.expr],
+                    op: bool_op,
+                    range: lhs_range,
+                })
+                .into();
+                continue;
+            }
+
             let (op_bp, op, associativity) = self.current_op();
             if op_bp < bp {
                 break;
@@ -2846,6 +3650,7 @@ impl<'src> Parser<'src> {
             });
         }
 
+        self.recursion_depth -= 1;
         (lhs, lhs_range)
     }
 
@@ -2853,6 +3658,10 @@ impl<'src> Parser<'src> {
         let token = self.next_token();
         let (mut lhs, mut lhs_range) = match token.0 {
             Tok::Plus | Tok::Minus | Tok::Not | Tok::Tilde => self.parse_unary_expr(token),
+            Tok::Exclamation => self.parse_c_style_not_expr(token),
+            Tok::Slash if self.is_c_style_comment_start(token.1) => {
+                self.parse_c_style_comment_expr(token)
+            }
             Tok::Star => self.parse_starred_expr(token),
             Tok::Await => self.parse_await_expr(token.1),
             Tok::Lambda => self.parse_lambda_expr(token.1),
@@ -2907,11 +3716,66 @@ impl<'src> Parser<'src> {
             }),
             Tok::None => Expr::NoneLiteral(ast::ExprNoneLiteral { range }),
             Tok::Ellipsis => Expr::EllipsisLiteral(ast::ExprEllipsisLiteral { range }),
+            // `True`/`False`/`None` are lexed as their own dedicated `Tok` variants above, so a
+            // `Name` token can only reach this arm with one of these spellings if it differs from
+            // the canonical casing (e.g. `true`, `TRUE`) or is a common alias from another
+            // language (`null`, `nil` for `None`). Treat it as the literal the user almost
+            // certainly meant. Unlike the `match`/`case` soft keywords below, which still parse
+            // as plain names, a real identifier spelled this way is shadowed and reported.
+            Tok::Name { name } if matches!(name.to_ascii_lowercase().as_str(), "true" | "false" | "none" | "null" | "nil") =>
+            {
+                let (canonical, applicability, expr) = match name.to_ascii_lowercase().as_str() {
+                    "true" => (
+                        "True",
+                        Applicability::MachineApplicable,
+                        Expr::BooleanLiteral(ast::ExprBooleanLiteral { value: true, range }),
+                    ),
+                    "false" => (
+                        "False",
+                        Applicability::MachineApplicable,
+                        Expr::BooleanLiteral(ast::ExprBooleanLiteral { value: false, range }),
+                    ),
+                    "none" => (
+                        "None",
+                        Applicability::MachineApplicable,
+                        Expr::NoneLiteral(ast::ExprNoneLiteral { range }),
+                    ),
+                    _ => (
+                        "None",
+                        Applicability::MaybeIncorrect,
+                        Expr::NoneLiteral(ast::ExprNoneLiteral { range }),
+                    ),
+                };
+                self.add_error_with_suggestion(
+                    ParseErrorType::OtherError(format!(
+                        "`{name}` is not a Python keyword; did you mean `{canonical}`?"
+                    )),
+                    range,
+                    ParseSuggestion {
+                        range,
+                        replacement: canonical.to_string(),
+                        applicability,
+                    },
+                );
+                expr
+            }
             Tok::Name { name } => Expr::Name(ast::ExprName {
                 id: name,
                 ctx: ExprContext::Load,
                 range,
             }),
+            // `match`/`case` are soft keywords and remain valid identifiers outside of a
+            // `match` statement's header/case clauses.
+            Tok::Match => Expr::Name(ast::ExprName {
+                id: "match".to_string(),
+                ctx: ExprContext::Load,
+                range,
+            }),
+            Tok::Case => Expr::Name(ast::ExprName {
+                id: "case".to_string(),
+                ctx: ExprContext::Load,
+                range,
+            }),
             Tok::IpyEscapeCommand { value, kind } if self.mode == Mode::Ipython => {
                 Expr::IpyEscapeCommand(ast::ExprIpyEscapeCommand { range, kind, value })
             }
@@ -3009,11 +3873,13 @@ impl<'src> Parser<'src> {
         let mut keywords: Vec<ast::Keyword> = vec![];
         let mut has_seen_kw_arg = false;
         let mut has_seen_kw_unpack = false;
+        let mut first_keyword_start: Option<TextSize> = None;
 
         let range = self.parse_delimited(
             true,
             TokenKind::Lpar,
             TokenKind::Comma,
+            SequenceRecovery::InsertMissingDelim,
             TokenKind::Rpar,
             |parser| {
                 if parser.at(TokenKind::DoubleStar) {
@@ -3033,8 +3899,27 @@ impl<'src> Parser<'src> {
 
                     match parser.current_kind() {
                         TokenKind::Async | TokenKind::For => {
-                            (parsed_expr, _) =
+                            let genexp_range;
+                            (parsed_expr, genexp_range) =
                                 parser.parse_generator_expr(parsed_expr.expr, expr_range);
+
+                            // A bare (unparenthesized) generator expression is only legal when
+                            // it's the call's sole argument, e.g. `any(x for x in xs)`. Arguments
+                            // before it are already in `args`/`keywords`, and a `,` right after it
+                            // means it isn't the last thing in the list, so both are checked here.
+                            if !args.is_empty() || !keywords.is_empty() || parser.at(TokenKind::Comma) {
+                                parser.add_error_with_suggestion(
+                                    ParseErrorType::OtherError(
+                                        "generator expression must be parenthesized if not sole argument".to_string(),
+                                    ),
+                                    genexp_range,
+                                    ParseSuggestion {
+                                        range: genexp_range,
+                                        replacement: format!("({})", parser.src_text(genexp_range)),
+                                        applicability: Applicability::MachineApplicable,
+                                    },
+                                );
+                            }
                         }
                         _ => {}
                     }
@@ -3043,20 +3928,27 @@ impl<'src> Parser<'src> {
                         parser.add_error(ParseErrorType::UnpackedArgumentError, expr_range);
                     }
 
+                    let eq_range = parser.current_range();
                     if parser.eat(TokenKind::Equal) {
                         has_seen_kw_arg = true;
+                        first_keyword_start.get_or_insert(expr_range.start());
                         let arg = if let Expr::Name(ident_expr) = parsed_expr.expr {
                             ast::Identifier {
                                 id: ident_expr.id,
                                 range: ident_expr.range,
                             }
                         } else {
-                            parser.add_error(
+                            parser.add_error_with_suggestion(
                                 ParseErrorType::OtherError(format!(
                                     "`{}` cannot be used as a keyword argument!",
                                     parser.src_text(expr_range)
                                 )),
                                 expr_range,
+                                ParseSuggestion {
+                                    range: expr_range.cover(eq_range),
+                                    replacement: String::new(),
+                                    applicability: Applicability::MaybeIncorrect,
+                                },
                             );
                             ast::Identifier {
                                 id: String::new(),
@@ -3075,7 +3967,27 @@ impl<'src> Parser<'src> {
                         if has_seen_kw_arg
                             && !(has_seen_kw_unpack || matches!(parsed_expr.expr, Expr::Starred(_)))
                         {
-                            parser.add_error(ParseErrorType::PositionalArgumentError, expr_range);
+                            if let Some(keywords_start) = first_keyword_start {
+                                let reorder_range = TextRange::new(keywords_start, expr_range.end());
+                                let keywords_text = parser
+                                    .src_text(TextRange::new(keywords_start, expr_range.start()))
+                                    .trim_end_matches([',', ' '])
+                                    .to_string();
+                                parser.add_error_with_suggestion(
+                                    ParseErrorType::PositionalArgumentError,
+                                    expr_range,
+                                    ParseSuggestion {
+                                        range: reorder_range,
+                                        replacement: format!(
+                                            "{}, {keywords_text}",
+                                            parser.src_text(expr_range)
+                                        ),
+                                        applicability: Applicability::MaybeIncorrect,
+                                    },
+                                );
+                            } else {
+                                parser.add_error(ParseErrorType::PositionalArgumentError, expr_range);
+                            }
                         }
                         args.push(parsed_expr.expr);
                     }
@@ -3098,6 +4010,7 @@ impl<'src> Parser<'src> {
     }
 
     fn parse_subscript_expr(&mut self, mut value: Expr, value_range: TextRange) -> ExprWithRange {
+        let open_bracket_range = self.current_range();
         assert!(self.eat(TokenKind::Lsqb));
 
         // To prevent the `value` context from being `Del` within a `del` statement,
@@ -3111,7 +4024,16 @@ impl<'src> Parser<'src> {
 
             let range = value_range.cover(close_bracket_range);
             let slice_range = close_bracket_range.sub_start(1.into());
-            self.add_error(ParseErrorType::EmptySlice, range);
+            let brackets_range = open_bracket_range.cover(close_bracket_range);
+            self.add_error_with_suggestion(
+                ParseErrorType::EmptySlice,
+                range,
+                ParseSuggestion {
+                    range: brackets_range,
+                    replacement: String::new(),
+                    applicability: Applicability::MachineApplicable,
+                },
+            );
             return (
                 Expr::Subscript(ast::ExprSubscript {
                     value: Box::new(value),
@@ -3136,6 +4058,7 @@ impl<'src> Parser<'src> {
                 .parse_separated(
                     true,
                     TokenKind::Comma,
+                    SequenceRecovery::InsertMissingDelim,
                     TokenSet::new(&[TokenKind::Rsqb]),
                     |parser| {
                         let (slice, slice_range) = parser.parse_slice();
@@ -3249,6 +4172,86 @@ impl<'src> Parser<'src> {
         )
     }
 
+    /// Recovers from the common mistake of using C/JavaScript-style `!flag` instead of
+    /// `not flag`.
+    fn parse_c_style_not_expr(&mut self, (_, range): Spanned) -> ExprWithRange {
+        self.add_error_with_suggestion(
+            ParseErrorType::OtherError(
+                "`!` is not a valid operator in Python; use `not` instead".to_string(),
+            ),
+            range,
+            ParseSuggestion {
+                range,
+                replacement: "not ".to_string(),
+                applicability: Applicability::MachineApplicable,
+            },
+        );
+
+        let (rhs, rhs_range) = self.expr_bp(6);
+        let new_range = range.cover(rhs_range);
+
+        (
+            Expr::UnaryOp(ast::ExprUnaryOp {
+                op: UnaryOp::Not,
+                operand: Box::new(rhs.expr),
+                range: new_range,
+            })
+            .into(),
+            new_range,
+        )
+    }
+
+    /// Returns `true` if `slash_range` (a `/` token we've just consumed) is immediately
+    /// followed by a `*`, i.e. it looks like the start of a C-style `/* ... */` comment.
+    fn is_c_style_comment_start(&mut self, slash_range: TextRange) -> bool {
+        self.at(TokenKind::Star) && self.current_range().start() == slash_range.end()
+    }
+
+    /// Recovers from a C-style `/* ... */` comment. The lexer has no notion of these (Python
+    /// only has `#` comments), so its contents have already been tokenized as ordinary Python
+    /// tokens; we can't re-lex them, so we skip tokens on a best-effort basis until we find a
+    /// closing `*/` or run out of logical line.
+    fn parse_c_style_comment_expr(&mut self, (_, slash_range): Spanned) -> ExprWithRange {
+        self.bump(TokenKind::Star);
+        let mut range = slash_range.cover(self.current_range());
+
+        loop {
+            if self.at_ts(NEWLINE_EOF_SET) {
+                break;
+            }
+            if self.at(TokenKind::Star) && self.peek_nth(1).0 == TokenKind::Slash {
+                range = range.cover(self.current_range());
+                self.bump(TokenKind::Star);
+                range = range.cover(self.current_range());
+                self.bump(TokenKind::Slash);
+                break;
+            }
+            range = range.cover(self.current_range());
+            self.next_token();
+        }
+
+        self.add_error_with_suggestion(
+            ParseErrorType::OtherError(
+                "C-style comments (`/* ... */`) aren't supported; use `#` instead".to_string(),
+            ),
+            range,
+            ParseSuggestion {
+                range,
+                replacement: String::new(),
+                applicability: Applicability::MaybeIncorrect,
+            },
+        );
+
+        (
+            Expr::Invalid(ast::ExprInvalid {
+                value: self.src_text(range).into(),
+                range,
+            })
+            .into(),
+            range,
+        )
+    }
+
     fn parse_attribute_expr(&mut self, value: Expr, lhs_range: TextRange) -> ExprWithRange {
         assert!(self.eat(TokenKind::Dot));
 
@@ -3530,6 +4533,27 @@ impl<'src> Parser<'src> {
 
     const FSTRING_END_SET: TokenSet =
         TokenSet::new(&[TokenKind::FStringEnd, TokenKind::Rbrace]).union(NEWLINE_EOF_SET);
+    /// Parses the literal/expression elements that make up an f-string's body (or, recursively,
+    /// a format spec's body -- a format spec can itself contain replacement fields, e.g.
+    /// `f"{x:{width}}"`, which is why [`Parser::parse_fstring_expr_element`] calls back into this
+    /// function for its `format_spec`). Nested format specs and arbitrarily nested expressions
+    /// (including dict/set/list literals and other f-strings inside a replacement field) are
+    /// already supported here, since a replacement field's value is parsed through the normal
+    /// `parse_exprs` grammar and recurses back into this function for its format spec.
+    ///
+    /// What this function (and this crate) cannot do is reuse the enclosing quote character
+    /// inside a replacement field (e.g. `f"{d["key"]}"`), allow multi-line or
+    /// backslash-containing expressions inside `{...}`, or gate either of those behind the
+    /// target Python version: all three depend on how `FStringStart`/`FStringMiddle`/`FStringEnd`
+    /// tokens are produced and on a target-version setting, and neither the lexer
+    /// (`ruff_python_parser::lexer`) nor any version configuration exists in this checkout for
+    /// this function to depend on.
+    ///
+    /// PEP 701 status: this is the parser-reachable half of full PEP 701 support and nothing
+    /// more. The lexer-level half -- same-quote reuse, multi-line/backslash expressions, and
+    /// version-gating either -- is genuinely unimplemented here, not merely undocumented; treat
+    /// full PEP 701 support as still outstanding until `ruff_python_parser::lexer` (or whatever
+    /// produces these f-string tokens in a complete checkout) exists for a follow-up to extend.
     fn parse_fstring_elements(&mut self) -> (Vec<FStringElement>, TextRange) {
         let mut elements = vec![];
         let mut final_range: Option<TextRange> = None;
@@ -3575,11 +4599,14 @@ impl<'src> Parser<'src> {
                     self.next_token();
                     continue;
                 }
-                // Handle an unexpected token
+                // Handle an unexpected token. Skip straight to the end of the f-string body (its
+                // `FStringEnd`, a `}`, or a newline) instead of bumping a single token at a time,
+                // so one malformed run of tokens produces one diagnostic instead of a cascade of
+                // them; any elements after it in the same body are skipped too.
                 _ => {
-                    let (tok, range) = self.next_token();
+                    let range = self.skip_until(Self::FSTRING_END_SET);
                     self.add_error(
-                        ParseErrorType::OtherError(format!("f-string: unexpected token `{tok:?}`")),
+                        ParseErrorType::OtherError("f-string: unexpected tokens".to_string()),
                         range,
                     );
                     continue;
@@ -3607,9 +4634,14 @@ impl<'src> Parser<'src> {
             "f-string: expecting expression",
         );
         if !value.is_parenthesized && matches!(value.expr, Expr::Lambda(_)) {
-            self.add_error(
+            self.add_error_with_suggestion(
                 ParseErrorType::FStringError(FStringErrorType::LambdaWithoutParentheses),
                 value_range,
+                ParseSuggestion {
+                    range: value_range,
+                    replacement: format!("({})", self.src_text(value_range)),
+                    applicability: Applicability::MachineApplicable,
+                },
             );
         }
         let debug_text = if self.eat(TokenKind::Equal) {
@@ -3632,9 +4664,14 @@ impl<'src> Parser<'src> {
                 "r" => ConversionFlag::Repr,
                 "a" => ConversionFlag::Ascii,
                 _ => {
-                    self.add_error(
+                    self.add_error_with_suggestion(
                         ParseErrorType::FStringError(FStringErrorType::InvalidConversionFlag),
                         range,
+                        ParseSuggestion {
+                            range,
+                            replacement: "s".to_string(),
+                            applicability: Applicability::HasPlaceholders,
+                        },
                     );
                     ConversionFlag::None
                 }
@@ -3657,9 +4694,14 @@ impl<'src> Parser<'src> {
 
         let close_brace_range = self.current_range();
         if has_open_brace && !self.eat(TokenKind::Rbrace) {
-            self.add_error(
+            self.add_error_with_suggestion(
                 ParseErrorType::FStringError(FStringErrorType::UnclosedLbrace),
                 close_brace_range,
+                ParseSuggestion {
+                    range: TextRange::empty(self.last_token_end),
+                    replacement: "}".to_string(),
+                    applicability: Applicability::MachineApplicable,
+                },
             );
         }
 
@@ -3677,7 +4719,10 @@ impl<'src> Parser<'src> {
         if self.at_ts(NEWLINE_EOF_SET) {
             let range = self.current_range();
             self.add_error(
-                ParseErrorType::OtherError("missing closing bracket `]`".to_string()),
+                ParseErrorType::UnclosedDelimiter {
+                    opener: open_bracket_range,
+                    expected: TokenKind::Rsqb,
+                },
                 range,
             );
         }
@@ -3728,7 +4773,10 @@ impl<'src> Parser<'src> {
         if self.at_ts(NEWLINE_EOF_SET) {
             let range = self.current_range();
             self.add_error(
-                ParseErrorType::OtherError("missing closing brace `}`".to_string()),
+                ParseErrorType::UnclosedDelimiter {
+                    opener: lbrace_range,
+                    expected: TokenKind::Rbrace,
+                },
                 range,
             );
         }
@@ -3750,6 +4798,11 @@ impl<'src> Parser<'src> {
             );
         }
 
+        // Taken before the first element, so that if the `:` arm below decides the dict
+        // interpretation it committed to came back dirty, it can rewind all the way here and
+        // reparse the first element fresh as part of a set instead.
+        let first_elt_checkpoint = self.checkpoint();
+
         let (mut parsed_expr, mut expr_range) = if self.eat(TokenKind::DoubleStar) {
             // Handle dict unpack
             let (value, _) = self.parse_expr();
@@ -3764,16 +4817,45 @@ impl<'src> Parser<'src> {
                     self.parse_set_comprehension_expr(parsed_expr.expr, expr_range);
             }
             TokenKind::Colon => {
+                // A `:` here means this can only be a dict (a set element can't have one), but
+                // malformed input can still make a clean dict parse raise errors (e.g. a stray
+                // colon inside what was actually meant to be a set, like `{a: b, c}`). If the
+                // dict interpretation comes back dirty, rewind all the way to the first element
+                // and see whether parsing the whole body as a set instead (colon and all)
+                // produces a cleaner diagnostic.
+                let errors_before = first_elt_checkpoint.errors_len;
+
                 self.next_token();
                 let (value, value_range) = self.parse_expr();
                 let range = expr_range.cover(value_range);
 
-                (parsed_expr, expr_range) = match self.current_kind() {
+                let (dict_expr, dict_range) = match self.current_kind() {
                     TokenKind::Async | TokenKind::For => {
                         self.parse_dict_comprehension_expr(parsed_expr.expr, value.expr, range)
                     }
                     _ => self.parse_dict_expr(Some(parsed_expr.expr), value.expr),
                 };
+                let dict_errors = self.errors.split_off(errors_before);
+
+                (parsed_expr, expr_range) = if dict_errors.is_empty() {
+                    self.errors.extend(dict_errors);
+                    (dict_expr, dict_range)
+                } else {
+                    let after_dict = self.checkpoint();
+                    self.rewind(first_elt_checkpoint);
+
+                    let (first_elt, _) = self.parse_expr2();
+                    let (set_expr, set_range) = self.parse_set_expr(first_elt.expr);
+                    let set_errors = self.errors.len() - errors_before;
+
+                    if set_errors < dict_errors.len() {
+                        (set_expr, set_range)
+                    } else {
+                        self.rewind(after_dict);
+                        self.errors.extend(dict_errors);
+                        (dict_expr, dict_range)
+                    }
+                };
             }
             _ if !matches!(parsed_expr.expr, Expr::Dict(_)) => {
                 (parsed_expr, expr_range) = self.parse_set_expr(parsed_expr.expr);
@@ -3817,7 +4899,10 @@ impl<'src> Parser<'src> {
         if self.at_ts(NEWLINE_EOF_SET) {
             let range = self.current_range();
             self.add_error(
-                ParseErrorType::OtherError("missing closing parenthesis `)`".to_string()),
+                ParseErrorType::UnclosedDelimiter {
+                    opener: open_paren_range,
+                    expected: TokenKind::Rpar,
+                },
                 range,
             );
         }
@@ -3891,7 +4976,7 @@ impl<'src> Parser<'src> {
         let mut elts = vec![first_element];
 
         final_range = final_range.cover(
-            self.parse_separated(true, TokenKind::Comma, Self::END_SEQUENCE_SET, |parser| {
+            self.parse_separated(true, TokenKind::Comma, SequenceRecovery::InsertMissingDelim, Self::END_SEQUENCE_SET, |parser| {
                 let (parsed_expr, range) = parse_func(parser);
                 elts.push(parsed_expr.expr);
                 range
@@ -3917,7 +5002,7 @@ impl<'src> Parser<'src> {
         let mut elts = vec![first_element];
 
         let range = self
-            .parse_separated(true, TokenKind::Comma, Self::END_SEQUENCE_SET, |parser| {
+            .parse_separated(true, TokenKind::Comma, SequenceRecovery::InsertMissingDelim, Self::END_SEQUENCE_SET, |parser| {
                 let (parsed_expr, range) = parser.parse_expr2();
                 elts.push(parsed_expr.expr);
                 range
@@ -3944,7 +5029,7 @@ impl<'src> Parser<'src> {
         let mut elts = vec![first_element];
 
         let range = self
-            .parse_separated(true, TokenKind::Comma, Self::END_SEQUENCE_SET, |parser| {
+            .parse_separated(true, TokenKind::Comma, SequenceRecovery::InsertMissingDelim, Self::END_SEQUENCE_SET, |parser| {
                 let (parsed_expr, range) = parser.parse_expr2();
                 elts.push(parsed_expr.expr);
                 range
@@ -3965,7 +5050,7 @@ impl<'src> Parser<'src> {
         let mut values = vec![value];
 
         let range = self
-            .parse_separated(true, TokenKind::Comma, Self::END_SEQUENCE_SET, |parser| {
+            .parse_separated(true, TokenKind::Comma, SequenceRecovery::InsertMissingDelim, Self::END_SEQUENCE_SET, |parser| {
                 if parser.eat(TokenKind::DoubleStar) {
                     keys.push(None);
                 } else {
@@ -4036,7 +5121,8 @@ impl<'src> Parser<'src> {
 
         let mut ifs = vec![];
         while self.eat(TokenKind::If) {
-            let (if_expr, if_range) = self.parse_expr_simple();
+            let if_with_range = self.parse_expr_simple();
+            let (if_expr, if_range) = self.recover_eq_as_eqeq(if_with_range);
             ifs.push(if_expr.expr);
             range = range.cover(if_range);
         }
@@ -4063,7 +5149,24 @@ impl<'src> Parser<'src> {
         (generators, range)
     }
 
+    /// Starred expressions (`*x`) parse fine as a standalone expression, but aren't allowed as a
+    /// comprehension's element, e.g. `[*a for a in b]` is invalid even though `*a` alone isn't.
+    /// This mirrors the dict-unpack-in-comprehension check in `parse_bracesized_expr`, just for the more
+    /// common iterable-unpacking case.
+    fn check_comprehension_elt(&mut self, elt: &Expr, elt_range: TextRange) {
+        if matches!(elt, Expr::Starred(_)) {
+            self.add_error(
+                ParseErrorType::OtherError(format!(
+                    "starred expression `{}` cannot be used as a comprehension element",
+                    self.src_text(elt_range)
+                )),
+                elt_range,
+            );
+        }
+    }
+
     fn parse_generator_expr(&mut self, element: Expr, element_range: TextRange) -> ExprWithRange {
+        self.check_comprehension_elt(&element, element_range);
         let (generators, range) = self.parse_generators(element_range);
 
         (
@@ -4082,6 +5185,7 @@ impl<'src> Parser<'src> {
         element: Expr,
         element_range: TextRange,
     ) -> ExprWithRange {
+        self.check_comprehension_elt(&element, element_range);
         let (generators, range) = self.parse_generators(element_range);
 
         (
@@ -4101,6 +5205,8 @@ impl<'src> Parser<'src> {
         value: Expr,
         range: TextRange,
     ) -> ExprWithRange {
+        self.check_comprehension_elt(&key, key.range());
+        self.check_comprehension_elt(&value, value.range());
         let (generators, range) = self.parse_generators(range);
 
         (
@@ -4120,6 +5226,7 @@ impl<'src> Parser<'src> {
         element: Expr,
         element_range: TextRange,
     ) -> ExprWithRange {
+        self.check_comprehension_elt(&element, element_range);
         let (generators, range) = self.parse_generators(element_range);
 
         (
@@ -4236,10 +5343,47 @@ impl<'src> Parser<'src> {
         )
     }
 
+    /// Recovers from a bare `=` where `==` was intended, in a boolean context that isn't a full
+    /// statement condition and so doesn't go through [`Parser::parse_condition`] -- namely a
+    /// ternary `if`/`else` expression's test and a comprehension's `if` clause. Mirrors
+    /// `parse_condition`'s handling of the same mistake.
+    fn recover_eq_as_eqeq(&mut self, mut test: ExprWithRange) -> ExprWithRange {
+        if self.at(TokenKind::Equal) {
+            let eq_range = self.current_range();
+            self.bump(TokenKind::Equal);
+
+            self.add_error_with_suggestion(
+                ParseErrorType::AssignmentInCondition,
+                eq_range,
+                ParseSuggestion {
+                    range: eq_range,
+                    replacement: "==".to_string(),
+                    applicability: Applicability::MachineApplicable,
+                },
+            );
+
+            let (rhs, rhs_range) = self.parse_expr_simple();
+            let range = test.1.cover(rhs_range);
+            test = (
+                Expr::Compare(ast::ExprCompare {
+                    left: Box::new(test.0.expr),
+                    ops: vec![CmpOp::Eq],
+                    comparators: vec![rhs.expr],
+                    range,
+                })
+                .into(),
+                range,
+            );
+        }
+
+        test
+    }
+
     fn parse_if_expr(&mut self, body: Expr, body_range: TextRange) -> ExprWithRange {
         self.bump(TokenKind::If);
 
-        let (test, _) = self.parse_expr_simple();
+        let test_with_range = self.parse_expr_simple();
+        let (test, _) = self.recover_eq_as_eqeq(test_with_range);
 
         self.expect_and_recover(TokenKind::Else, TokenSet::EMPTY);
 
@@ -4324,6 +5468,30 @@ impl<'src> Parser<'src> {
         let annotation = if function_kind != FunctionKind::Lambda && self.eat(TokenKind::Colon) {
             let (ann, _) = self.parse_expr();
             Some(Box::new(ann.expr))
+        } else if function_kind == FunctionKind::Lambda && self.at(TokenKind::Colon) {
+            // `lambda x: int: x` -- annotating a `lambda` parameter the way a `def` parameter
+            // would be -- otherwise misparses as a lambda body starting at `int` with a stray
+            // second `:` left dangling. Speculatively parse past the first `:` as if it were an
+            // annotation; if a second `:` immediately follows, this was indeed a mistaken
+            // annotation, so report it and discard it. Otherwise rewind, so the first `:` is
+            // left untouched for the caller to consume as the real body separator.
+            let checkpoint = self.checkpoint();
+            let colon_range = self.current_range();
+            self.bump(TokenKind::Colon);
+            let (_, annotation_range) = self.parse_expr();
+
+            if self.at(TokenKind::Colon) {
+                self.add_error(
+                    ParseErrorType::OtherError(
+                        "annotations are not allowed on `lambda` parameters".to_string(),
+                    ),
+                    colon_range.cover(annotation_range),
+                );
+                None
+            } else {
+                self.rewind(checkpoint);
+                None
+            }
         } else {
             None
         };
@@ -4366,6 +5534,17 @@ impl<'src> Parser<'src> {
         let mut has_seen_asterisk = false;
         let mut has_seen_vararg = false;
         let mut has_seen_default_param = false;
+        // Set when we've just seen a bare `*` separator (not `*args`) and cleared as soon as a
+        // keyword-only parameter follows it. If it's still set by the time we reach `**kwargs`,
+        // the `*` has no keyword-only parameters after it, e.g. `def f(*, **kwargs)`, which is
+        // invalid -- a bare `*` only makes sense as a marker ahead of at least one kwonly param.
+        let mut bare_star_range: Option<TextRange> = None;
+        // Start of the first `*` token we've seen, used to build a "swap `/` and `*`"
+        // suggestion if a `/` shows up after it.
+        let mut asterisk_start: Option<TextSize> = None;
+        // Start of the first parameter that carries a default, used to build a "move this
+        // parameter before the defaulted ones" suggestion for `DefaultArgumentError`.
+        let mut first_default_param_start: Option<TextSize> = None;
 
         let ending = match function_kind {
             FunctionKind::Lambda => TokenKind::Colon,
@@ -4375,34 +5554,63 @@ impl<'src> Parser<'src> {
         let ending_set = TokenSet::new(&[TokenKind::Rarrow, ending]).union(COMPOUND_STMT_SET);
         let start = self.node_start();
 
-        self.parse_separated(true, TokenKind::Comma, ending_set, |parser| {
-            // Don't allow any parameter after we have seen a vararg `**kwargs`
-            if has_seen_vararg {
-                parser.add_error(
-                    ParseErrorType::ParamFollowsVarKeywordParam,
-                    parser.current_range(),
-                );
-            }
+        self.parse_separated(true, TokenKind::Comma, SequenceRecovery::Forbid, ending_set, |parser| {
+            // Don't allow any parameter after we have seen a vararg `**kwargs`. Capture whether
+            // this was already true *before* this parameter, since `has_seen_vararg` itself may
+            // flip to `true` below if this parameter happens to be the `**kwargs` one.
+            let already_had_vararg = has_seen_vararg;
 
+            let param_start = parser.node_start();
             if parser.eat(TokenKind::Star) {
                 has_seen_asterisk = true;
+                asterisk_start.get_or_insert(param_start);
                 if parser.at(TokenKind::Comma) {
                     has_seen_default_param = false;
+                    bare_star_range = Some(parser.node_range(param_start));
                 } else if parser.at_expr() {
-                    let param = parser.parse_parameter(function_kind);
+                    let mut param = parser.parse_parameter(function_kind);
+                    param.range = TextRange::new(param_start, param.range.end());
                     vararg = Some(Box::new(param));
                 }
             } else if parser.eat(TokenKind::DoubleStar) {
                 has_seen_vararg = true;
-                let param = parser.parse_parameter(function_kind);
+                if let Some(range) = bare_star_range.take() {
+                    parser.add_error(
+                        ParseErrorType::OtherError(
+                            "named arguments must follow bare `*`".to_string(),
+                        ),
+                        range,
+                    );
+                }
+                let mut param = parser.parse_parameter(function_kind);
+                param.range = TextRange::new(param_start, param.range.end());
                 kwarg = Some(Box::new(param));
             } else if parser.eat(TokenKind::Slash) {
                 // Don't allow `/` after a `*`
                 if has_seen_asterisk {
-                    parser.add_error(
-                        ParseErrorType::OtherError("`/` must be ahead of `*`".to_string()),
-                        parser.current_range(),
-                    );
+                    let error_range = parser.current_range();
+                    let slash_range = TextRange::new(param_start, parser.last_token_end);
+                    if let Some(asterisk_start) = asterisk_start {
+                        let swap_range = TextRange::new(asterisk_start, slash_range.end());
+                        let asterisk_text = parser
+                            .src_text(TextRange::new(asterisk_start, slash_range.start()))
+                            .trim_end_matches([',', ' '])
+                            .to_string();
+                        parser.add_error_with_suggestion(
+                            ParseErrorType::OtherError("`/` must be ahead of `*`".to_string()),
+                            error_range,
+                            ParseSuggestion {
+                                range: swap_range,
+                                replacement: format!("/, {asterisk_text}"),
+                                applicability: Applicability::MaybeIncorrect,
+                            },
+                        );
+                    } else {
+                        parser.add_error(
+                            ParseErrorType::OtherError("`/` must be ahead of `*`".to_string()),
+                            error_range,
+                        );
+                    }
                 }
                 std::mem::swap(&mut args, &mut posonlyargs);
             } else if parser.at(TokenKind::Name) {
@@ -4411,11 +5619,35 @@ impl<'src> Parser<'src> {
                 // can't place `b` after `a=1`. Non-default parameters are only allowed after
                 // default parameters if we have a `*` before them, e.g. `a=1, *, b`.
                 if param.default.is_none() && has_seen_default_param && !has_seen_asterisk {
-                    parser.add_error(ParseErrorType::DefaultArgumentError, parser.current_range());
+                    if let Some(default_start) = first_default_param_start {
+                        let reorder_range = TextRange::new(default_start, param.range.end());
+                        let defaulted_text = parser
+                            .src_text(TextRange::new(default_start, param.range.start()))
+                            .trim_end_matches([',', ' '])
+                            .to_string();
+                        parser.add_error_with_suggestion(
+                            ParseErrorType::DefaultArgumentError,
+                            param.range,
+                            ParseSuggestion {
+                                range: reorder_range,
+                                replacement: format!(
+                                    "{}, {defaulted_text}",
+                                    parser.src_text(param.range)
+                                ),
+                                applicability: Applicability::MaybeIncorrect,
+                            },
+                        );
+                    } else {
+                        parser.add_error(ParseErrorType::DefaultArgumentError, param.range);
+                    }
+                }
+                if param.default.is_some() {
+                    first_default_param_start.get_or_insert(param.range.start());
                 }
                 has_seen_default_param = param.default.is_some();
 
                 if has_seen_asterisk {
+                    bare_star_range = None;
                     kwonlyargs.push(param);
                 } else {
                     args.push(param);
@@ -4426,12 +5658,30 @@ impl<'src> Parser<'src> {
                 }
 
                 let range = parser.current_range();
-                parser.skip_until(
+                let skipped_range = parser.skip_until(
                     ending_set.union([TokenKind::Comma, TokenKind::Colon].as_slice().into()),
                 );
-                parser.add_error(
-                    ParseErrorType::OtherError("expected parameter".to_string()),
-                    range.cover(parser.current_range()), // TODO(micha): This goes one token too far?
+                // If nothing was actually skipped, we're already sitting on the delimiter that
+                // stopped the search (e.g. `def f(, b):` or `def f(a,,b):`); point at an empty
+                // range at the gap itself rather than covering into that delimiter.
+                let error_range = if parser.current_range() == range {
+                    TextRange::empty(range.start())
+                } else {
+                    skipped_range
+                };
+                parser.add_error(ParseErrorType::MissingParameter, error_range);
+            }
+
+            if already_had_vararg {
+                let full_range = TextRange::new(param_start, parser.last_token_end);
+                parser.add_error_with_suggestion(
+                    ParseErrorType::ParamFollowsVarKeywordParam,
+                    full_range,
+                    ParseSuggestion {
+                        range: full_range,
+                        replacement: String::new(),
+                        applicability: Applicability::MaybeIncorrect,
+                    },
                 );
             }
 
@@ -4448,6 +5698,10 @@ impl<'src> Parser<'src> {
             kwarg,
         };
 
+        // `validate_parameters` is what reports duplicate parameter names (e.g. `def f(a, b, a)`).
+        // A two-span "duplicate parameter / previously defined here" diagnostic for it would use
+        // `ParseError::secondary_label`, but `validate_parameters` lives in `parser/helpers.rs`,
+        // which isn't part of this checkout, so that diagnostic isn't wired up here.
         if let Err(error) = helpers::validate_parameters(&parameters) {
             self.errors.push(error);
         }
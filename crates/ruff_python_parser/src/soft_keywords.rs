@@ -19,13 +19,31 @@ use crate::{token::Tok, Mode, Tokenized};
 ///
 /// Handling soft keywords in this intermediary pass allows us to simplify both the lexer and
 /// `ruff_python_parser`, as neither of them need to be aware of soft keywords.
+///
+/// Which tokens are soft keywords, the positions they're recognized in, and the lookahead used to
+/// tell a real occurrence apart from an identifier are all driven by a table of
+/// [`SoftKeywordRule`]s (see [`DEFAULT_SOFT_KEYWORD_RULES`]) rather than hard-coded into the loop
+/// below, so that a caller with its own set of soft keywords (e.g. a future Python grammar
+/// addition, or a dialect) can plug in a different table via
+/// [`SoftKeywordLexer::with_rules`] without touching this type.
 pub struct SoftKeywordLexer<'source> {
     underlying: PeekableLexer<'source>,
     position: Position,
+    rules: &'static [SoftKeywordRule],
 }
 
 impl<'source> SoftKeywordLexer<'source> {
     pub fn new(lexer: Lexer<'source>, mode: Mode) -> Self {
+        Self::with_rules(lexer, mode, DEFAULT_SOFT_KEYWORD_RULES)
+    }
+
+    /// Like [`SoftKeywordLexer::new`], but resolves soft keywords using `rules` instead of the
+    /// built-in `match`/`case`/`type` table.
+    pub fn with_rules(
+        lexer: Lexer<'source>,
+        mode: Mode,
+        rules: &'static [SoftKeywordRule],
+    ) -> Self {
         Self {
             underlying: PeekableLexer::new(lexer),
             position: if mode == Mode::Expression {
@@ -33,9 +51,13 @@ impl<'source> SoftKeywordLexer<'source> {
             } else {
                 Position::Statement
             },
+            rules,
         }
     }
 
+    // `Tokenized` doesn't carry a per-token `SoftKeywordResolution` slot, so this still collects
+    // via the unclassified `Iterator` impl; a consumer that needs the classification for every
+    // token in a file should drive `next_classified` directly instead of going through this.
     pub fn into_tokenized(mut self) -> Tokenized {
         let tokens: Vec<_> = self.by_ref().collect();
         Tokenized {
@@ -48,6 +70,15 @@ impl<'source> SoftKeywordLexer<'source> {
     pub fn into_errors(self) -> Vec<LexicalError> {
         self.underlying.lexer.into_errors()
     }
+
+    /// Like [`Iterator::next`], but also reports whether the yielded token was resolved as a
+    /// real soft keyword, demoted to a plain identifier, or isn't governed by any soft-keyword
+    /// rule to begin with. Useful for consumers, like a syntax highlighter, that need to tell
+    /// `match` in `match x:` apart from `match` in `match = 1` without re-running the lookahead
+    /// heuristic themselves.
+    pub fn next_classified(&mut self) -> Option<(Spanned, SoftKeywordResolution)> {
+        self.next_impl()
+    }
 }
 
 impl From<SoftKeywordLexer<'_>> for Tokenized {
@@ -61,93 +92,33 @@ impl Iterator for SoftKeywordLexer<'_> {
 
     #[inline]
     fn next(&mut self) -> Option<Spanned> {
+        self.next_impl().map(|(spanned, _)| spanned)
+    }
+}
+
+impl SoftKeywordLexer<'_> {
+    fn next_impl(&mut self) -> Option<(Spanned, SoftKeywordResolution)> {
         let mut next = self.underlying.next();
+        let mut resolution = SoftKeywordResolution::NotSoftKeyword;
         if let Some((tok, range)) = next.as_ref() {
-            // If the token is a soft keyword e.g. `type`, `match`, or `case`, check if it's
-            // used as an identifier. We assume every soft keyword use is an identifier unless
-            // a heuristic is met.
-            match tok {
-                // For `match` and `case`, all of the following conditions must be met:
-                // 1. The token is at the start of a logical line.
-                // 2. The logical line contains a top-level colon (that is, a colon that is not nested
-                //    inside a parenthesized expression, list, or dictionary).
-                // 3. The top-level colon is not the immediate sibling of a `match` or `case` token.
-                //    (This is to avoid treating `match` or `case` as identifiers when annotated with
-                //    type hints.)
-                Tok::Match | Tok::Case => {
-                    if matches!(self.position, Position::Statement) {
-                        let mut nesting = 0;
-                        let mut first = true;
-                        let mut seen_colon = false;
-                        let mut seen_lambda = false;
-                        while let Some((tok, _)) = self.underlying.peek() {
-                            match tok {
-                                Tok::Newline => break,
-                                Tok::Lambda if nesting == 0 => seen_lambda = true,
-                                Tok::Colon if nesting == 0 => {
-                                    if seen_lambda {
-                                        seen_lambda = false;
-                                    } else if !first {
-                                        seen_colon = true;
-                                    }
-                                }
-                                Tok::Lpar | Tok::Lsqb | Tok::Lbrace => nesting += 1,
-                                Tok::Rpar | Tok::Rsqb | Tok::Rbrace => nesting -= 1,
-                                _ => {}
-                            }
-                            first = false;
-                        }
-                        if !seen_colon {
-                            next = Some((soft_to_name(tok), *range));
-                        }
-                    } else {
-                        next = Some((soft_to_name(tok), *range));
-                    }
-                }
-                // For `type` all of the following conditions must be met:
-                // 1. The token is at the start of a logical line.
-                // 2. The type token is immediately followed by a name token.
-                // 3. The name token is eventually followed by an equality token.
-                Tok::Type => {
-                    if matches!(
-                        self.position,
-                        Position::Statement | Position::SimpleStatement
-                    ) {
-                        let mut is_type_alias = false;
-                        if let Some((tok, _)) = self.underlying.peek() {
-                            if matches!(
-                                tok,
-                                Tok::Name { .. } |
-                                // We treat a soft keyword token following a type token as a
-                                // name to support cases like `type type = int` or `type match = int`
-                                Tok::Type | Tok::Match | Tok::Case
-                            ) {
-                                let mut nesting = 0;
-                                while let Some((tok, _)) = self.underlying.peek() {
-                                    match tok {
-                                        Tok::Newline => break,
-                                        Tok::Equal if nesting == 0 => {
-                                            is_type_alias = true;
-                                            break;
-                                        }
-                                        Tok::Lsqb => nesting += 1,
-                                        Tok::Rsqb => nesting -= 1,
-                                        // Allow arbitrary content within brackets for now
-                                        _ if nesting > 0 => {}
-                                        // Exit if unexpected tokens are seen
-                                        _ => break,
-                                    }
-                                }
-                            }
-                        }
-                        if !is_type_alias {
-                            next = Some((soft_to_name(tok), *range));
-                        }
-                    } else {
-                        next = Some((soft_to_name(tok), *range));
-                    }
+            // If the token is governed by a soft-keyword rule, check whether it's used as an
+            // identifier. We assume every soft keyword use is an identifier unless the rule's
+            // heuristic says otherwise, and only once we're in a position the rule applies in.
+            if let Some(rule) = self.rules.iter().find(|rule| (rule.matches)(tok)) {
+                let resolved_as_keyword =
+                    rule.positions.contains(&self.position)
+                        && (rule.heuristic)(&mut self.underlying, rule.max_lookahead);
+                if resolved_as_keyword {
+                    resolution = SoftKeywordResolution::Keyword;
+                } else {
+                    resolution = SoftKeywordResolution::Identifier;
+                    next = Some((
+                        Tok::Name {
+                            name: rule.spelling.to_owned(),
+                        },
+                        *range,
+                    ));
                 }
-                _ => (), // Not a soft keyword token
             }
         }
 
@@ -200,25 +171,159 @@ impl Iterator for SoftKeywordLexer<'_> {
             }
         }
 
-        next
+        next.map(|spanned| (spanned, resolution))
+    }
+}
+
+/// Whether a token was resolved as a real soft keyword, demoted to a plain identifier, or doesn't
+/// participate in soft-keyword resolution at all (e.g. `Tok::Def`). See
+/// [`SoftKeywordLexer::next_classified`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoftKeywordResolution {
+    /// The token was resolved as the real soft keyword, e.g. `match` in `match x:`.
+    Keyword,
+    /// The token was demoted to a plain identifier, e.g. `match` in `match = 1`.
+    Identifier,
+    /// The token isn't governed by any soft-keyword rule.
+    NotSoftKeyword,
+}
+
+/// A single soft keyword's resolution rule: which token it governs, the identifier spelling to
+/// fall back to, the positions its heuristic applies in, and the heuristic itself.
+///
+/// Function pointers (rather than a trait object) keep the table `const`-constructible, so a
+/// whole rule set can live in a `&'static [SoftKeywordRule]` with no allocation.
+pub struct SoftKeywordRule {
+    /// Whether this rule governs `tok`.
+    pub matches: fn(tok: &Tok) -> bool,
+    /// The identifier spelling to use when this rule's heuristic decides the token isn't the
+    /// keyword, e.g. `"match"`.
+    pub spelling: &'static str,
+    /// The [`Position`]s this rule's heuristic is evaluated in. Outside of them, the token is
+    /// always resolved as a plain identifier without running the heuristic.
+    pub positions: &'static [Position],
+    /// The lookahead heuristic: given a peekable view of the tokens that follow and the maximum
+    /// number of tokens it may peek at before giving up, returns whether this occurrence should
+    /// be resolved as the real keyword (`true`) rather than an identifier (`false`).
+    pub heuristic: fn(lexer: &mut PeekableLexer, max_lookahead: usize) -> bool,
+    /// Upper bound on how many tokens this rule's heuristic will peek ahead before giving up and
+    /// falling back to the default interpretation (identifier). Without this, a soft-keyword
+    /// token at the start of a pathologically long logical line (e.g. a huge literal on one
+    /// statement) would force `PeekableLexer` to buffer the entire line before any of it could be
+    /// emitted.
+    pub max_lookahead: usize,
+}
+
+/// Default value for [`SoftKeywordRule::max_lookahead`], generous enough that it's never hit by
+/// normal code.
+pub const DEFAULT_MAX_SOFT_KEYWORD_LOOKAHEAD: usize = 256;
+
+/// The built-in soft-keyword table for `match`, `case`, and `type`.
+pub static DEFAULT_SOFT_KEYWORD_RULES: &[SoftKeywordRule] = &[
+    SoftKeywordRule {
+        matches: |tok| matches!(tok, Tok::Match),
+        spelling: "match",
+        positions: &[Position::Statement, Position::SimpleStatement],
+        heuristic: logical_line_colon_heuristic,
+        max_lookahead: DEFAULT_MAX_SOFT_KEYWORD_LOOKAHEAD,
+    },
+    SoftKeywordRule {
+        matches: |tok| matches!(tok, Tok::Case),
+        spelling: "case",
+        positions: &[Position::Statement, Position::SimpleStatement],
+        heuristic: logical_line_colon_heuristic,
+        max_lookahead: DEFAULT_MAX_SOFT_KEYWORD_LOOKAHEAD,
+    },
+    SoftKeywordRule {
+        matches: |tok| matches!(tok, Tok::Type),
+        spelling: "type",
+        positions: &[Position::Statement, Position::SimpleStatement],
+        heuristic: type_alias_heuristic,
+        max_lookahead: DEFAULT_MAX_SOFT_KEYWORD_LOOKAHEAD,
+    },
+];
+
+/// The `match`/`case` heuristic: resolves as the keyword if all of the following hold:
+/// 1. The logical line contains a top-level colon (that is, a colon that is not nested inside a
+///    parenthesized expression, list, or dictionary).
+/// 2. The top-level colon is not the immediate sibling of the `match`/`case` token. (This is to
+///    avoid treating `match` or `case` as identifiers when annotated with type hints.)
+///
+/// Gives up and resolves as an identifier after peeking `max_lookahead` tokens without finding a
+/// top-level colon, so a single pathologically long logical line can't force the whole line into
+/// memory before this function returns.
+fn logical_line_colon_heuristic(lexer: &mut PeekableLexer, max_lookahead: usize) -> bool {
+    let mut nesting = 0;
+    let mut first = true;
+    let mut seen_colon = false;
+    let mut seen_lambda = false;
+    let mut scanned = 0;
+    while let Some((tok, _)) = lexer.peek() {
+        scanned += 1;
+        if scanned > max_lookahead {
+            break;
+        }
+        match tok {
+            Tok::Newline => break,
+            Tok::Lambda if nesting == 0 => seen_lambda = true,
+            Tok::Colon if nesting == 0 => {
+                if seen_lambda {
+                    seen_lambda = false;
+                } else if !first {
+                    seen_colon = true;
+                }
+            }
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => nesting += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => nesting -= 1,
+            _ => {}
+        }
+        first = false;
     }
+    seen_colon
 }
 
-#[inline]
-fn soft_to_name(tok: &Tok) -> Tok {
-    let name = match tok {
-        Tok::Match => "match",
-        Tok::Case => "case",
-        Tok::Type => "type",
-        _ => unreachable!("other tokens never reach here"),
-    };
-    Tok::Name {
-        name: name.to_owned(),
+/// The `type` heuristic: resolves as the keyword if all of the following hold:
+/// 1. The `type` token is immediately followed by a name token (or another soft keyword, treated
+///    as a name here, to support cases like `type type = int` or `type match = int`).
+/// 2. That name token is eventually followed by an equality token.
+///
+/// Gives up and resolves as an identifier after peeking `max_lookahead` tokens without resolving
+/// the alias-or-not question, for the same reason [`logical_line_colon_heuristic`] does.
+fn type_alias_heuristic(lexer: &mut PeekableLexer, max_lookahead: usize) -> bool {
+    let mut is_type_alias = false;
+    if let Some((tok, _)) = lexer.peek() {
+        if matches!(
+            tok,
+            Tok::Name { .. } | Tok::Type | Tok::Match | Tok::Case
+        ) {
+            let mut nesting = 0;
+            let mut scanned = 0;
+            while let Some((tok, _)) = lexer.peek() {
+                scanned += 1;
+                if scanned > max_lookahead {
+                    break;
+                }
+                match tok {
+                    Tok::Newline => break,
+                    Tok::Equal if nesting == 0 => {
+                        is_type_alias = true;
+                        break;
+                    }
+                    Tok::Lsqb => nesting += 1,
+                    Tok::Rsqb => nesting -= 1,
+                    // Allow arbitrary content within brackets for now
+                    _ if nesting > 0 => {}
+                    // Exit if unexpected tokens are seen
+                    _ => break,
+                }
+            }
+        }
     }
+    is_type_alias
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Position {
+pub enum Position {
     /// The lexer is at the start of a logical line, i.e., the start of a simple or compound statement.
     Statement,
     /// The lexer is at the start of a simple statement, e.g., a statement following a semicolon
@@ -233,7 +338,7 @@ enum Position {
     Other,
 }
 
-struct PeekableLexer<'source> {
+pub(crate) struct PeekableLexer<'source> {
     lexer: Lexer<'source>,
     lookahead: VecDeque<Spanned>,
     lookahead_index: usize,
@@ -251,7 +356,7 @@ impl<'source> PeekableLexer<'source> {
     /// Peeks one token ahead.
     ///
     /// Calling the method multiple times works similar to `next` in that it peeks one token further ahead each time the function is called.
-    fn peek(&mut self) -> Option<&Spanned> {
+    pub(crate) fn peek(&mut self) -> Option<&Spanned> {
         let result = if self.lookahead_index < self.lookahead.len() {
             &self.lookahead[self.lookahead_index]
         } else if let Some(result) = self.lexer.next() {
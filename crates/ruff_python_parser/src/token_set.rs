@@ -0,0 +1,150 @@
+use crate::TokenKind;
+
+/// A bitset for [`TokenKind`], used for the purpose of fast membership testing. This is sometimes
+/// used as a performance optimization for "is this a token I'm looking for" checks, in lieu of
+/// slower alternatives like `Vec` or `HashSet`.
+///
+/// This is inspired by rust-analyzer's `TokenSet`: <https://github.com/rust-lang/rust-analyzer/blob/master/crates/parser/src/token_set.rs>
+#[derive(Clone, Copy)]
+pub(crate) struct TokenSet(u128);
+
+impl TokenSet {
+    pub(crate) const EMPTY: TokenSet = TokenSet(0);
+
+    pub(crate) const fn new(kinds: &[TokenKind]) -> TokenSet {
+        let mut res = 0u128;
+        let mut i = 0;
+        while i < kinds.len() {
+            res |= mask(kinds[i]);
+            i += 1;
+        }
+        TokenSet(res)
+    }
+
+    pub(crate) const fn union(self, other: TokenSet) -> TokenSet {
+        TokenSet(self.0 | other.0)
+    }
+
+    pub(crate) const fn contains(&self, kind: TokenKind) -> bool {
+        self.0 & mask(kind) != 0
+    }
+
+    /// Returns an iterator over the [`TokenKind`]s contained in this set. This is used to
+    /// flatten a set-based probe (`at_ts`) into the individual kinds that were tested, for
+    /// richer "expected one of" syntax error messages.
+    pub(crate) fn kinds(&self) -> impl Iterator<Item = TokenKind> + '_ {
+        ALL_TOKEN_KINDS
+            .iter()
+            .copied()
+            .filter(move |kind| self.contains(*kind))
+    }
+}
+
+/// Every [`TokenKind`] variant that can appear in a [`TokenSet`]. Used by [`TokenSet::kinds`]
+/// to turn a bitset back into the kinds it was built from.
+const ALL_TOKEN_KINDS: &[TokenKind] = &[
+    TokenKind::Amper,
+    TokenKind::And,
+    TokenKind::As,
+    TokenKind::Assert,
+    TokenKind::Async,
+    TokenKind::At,
+    TokenKind::Await,
+    TokenKind::Break,
+    TokenKind::Case,
+    TokenKind::CircumFlex,
+    TokenKind::Class,
+    TokenKind::Colon,
+    TokenKind::ColonEqual,
+    TokenKind::Comma,
+    TokenKind::Complex,
+    TokenKind::Continue,
+    TokenKind::Dedent,
+    TokenKind::Def,
+    TokenKind::Del,
+    TokenKind::Dot,
+    TokenKind::DoubleSlash,
+    TokenKind::DoubleStar,
+    TokenKind::Elif,
+    TokenKind::Ellipsis,
+    TokenKind::Else,
+    TokenKind::EndOfFile,
+    TokenKind::EqEqual,
+    TokenKind::Equal,
+    TokenKind::EscapeCommand,
+    TokenKind::Except,
+    TokenKind::Exclamation,
+    TokenKind::FStringEnd,
+    TokenKind::FStringMiddle,
+    TokenKind::FStringStart,
+    TokenKind::False,
+    TokenKind::Finally,
+    TokenKind::Float,
+    TokenKind::For,
+    TokenKind::From,
+    TokenKind::Global,
+    TokenKind::Greater,
+    TokenKind::GreaterEqual,
+    TokenKind::If,
+    TokenKind::Import,
+    TokenKind::In,
+    TokenKind::Indent,
+    TokenKind::Int,
+    TokenKind::Is,
+    TokenKind::Lambda,
+    TokenKind::Lbrace,
+    TokenKind::LeftShift,
+    TokenKind::Less,
+    TokenKind::LessEqual,
+    TokenKind::Lpar,
+    TokenKind::Lsqb,
+    TokenKind::Match,
+    TokenKind::Minus,
+    TokenKind::Name,
+    TokenKind::Newline,
+    TokenKind::None,
+    TokenKind::Nonlocal,
+    TokenKind::Not,
+    TokenKind::NotEqual,
+    TokenKind::Or,
+    TokenKind::Pass,
+    TokenKind::Percent,
+    TokenKind::Plus,
+    TokenKind::Question,
+    TokenKind::Raise,
+    TokenKind::Rarrow,
+    TokenKind::Rbrace,
+    TokenKind::Return,
+    TokenKind::RightShift,
+    TokenKind::Rpar,
+    TokenKind::Rsqb,
+    TokenKind::Semi,
+    TokenKind::Slash,
+    TokenKind::Star,
+    TokenKind::String,
+    TokenKind::Tilde,
+    TokenKind::True,
+    TokenKind::Try,
+    TokenKind::Type,
+    TokenKind::Unknown,
+    TokenKind::Vbar,
+    TokenKind::While,
+    TokenKind::With,
+    TokenKind::Yield,
+];
+
+const fn mask(kind: TokenKind) -> u128 {
+    1u128 << (kind as u8 as u32)
+}
+
+impl From<&[TokenKind]> for TokenSet {
+    fn from(kinds: &[TokenKind]) -> Self {
+        TokenSet::new(kinds)
+    }
+}
+
+impl<const N: usize> From<[TokenKind; N]> for TokenSet {
+    fn from(kinds: [TokenKind; N]) -> Self {
+        TokenSet::new(&kinds)
+    }
+}
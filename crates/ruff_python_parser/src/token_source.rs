@@ -0,0 +1,94 @@
+use crate::lexer::{LexResult, LexicalError, Spanned};
+
+/// A source of tokens backed by the fully-lexed token stream for a source file.
+///
+/// Because the parser receives the output of the lexer as a single `Vec<LexResult>` up front
+/// (see [`crate::parser::parse_tokens`]), the "source" is really just a cursor into that vector.
+/// That makes checkpointing trivial: a [`TokenSourceCheckpoint`] is nothing more than the cursor
+/// position and the number of lexical errors recorded so far, and rewinding is just restoring
+/// both.
+pub(crate) struct TokenSource {
+    tokens: Vec<LexResult>,
+    /// Index of the next token to be yielded by [`TokenSource::next`].
+    cursor: usize,
+    /// Lexical errors encountered so far, in the order they were consumed via `next`. Errors
+    /// that are only seen via `peek_nth` aren't recorded here until the cursor actually reaches
+    /// them, so that speculative parsing followed by a `rewind` doesn't double-report them.
+    errors: Vec<LexicalError>,
+}
+
+/// An opaque snapshot of a [`TokenSource`]'s position, created by [`TokenSource::checkpoint`]
+/// and restored with [`TokenSource::rewind`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TokenSourceCheckpoint {
+    cursor: usize,
+    errors_len: usize,
+}
+
+impl TokenSource {
+    pub(crate) fn new(tokens: Vec<LexResult>) -> Self {
+        TokenSource {
+            tokens,
+            cursor: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Returns the next token, advancing the cursor. Lexical errors are recorded and skipped
+    /// over; the caller only ever sees successfully lexed tokens.
+    pub(crate) fn next(&mut self) -> Option<Spanned> {
+        loop {
+            let result = self.tokens.get(self.cursor)?.clone();
+            self.cursor += 1;
+
+            match result {
+                Ok(spanned) => return Some(spanned),
+                Err(error) => self.errors.push(error),
+            }
+        }
+    }
+
+    /// Peeks the `offset`-th token after the cursor (0-indexed) without advancing it. Lexical
+    /// errors encountered while peeking are skipped but, unlike `next`, are not recorded --
+    /// they're recorded only once the cursor actually reaches them.
+    pub(crate) fn peek_nth(&self, offset: usize) -> Option<Spanned> {
+        let mut index = self.cursor;
+        let mut remaining = offset;
+
+        loop {
+            let result = self.tokens.get(index)?.clone();
+            index += 1;
+
+            match result {
+                Ok(spanned) => {
+                    if remaining == 0 {
+                        return Some(spanned);
+                    }
+                    remaining -= 1;
+                }
+                Err(_) => continue,
+            }
+        }
+    }
+
+    /// Captures the current cursor position and error count so that parsing can later be
+    /// rewound to this point via [`TokenSource::rewind`].
+    pub(crate) fn checkpoint(&self) -> TokenSourceCheckpoint {
+        TokenSourceCheckpoint {
+            cursor: self.cursor,
+            errors_len: self.errors.len(),
+        }
+    }
+
+    /// Restores the cursor and error list to a previously captured [`TokenSourceCheckpoint`],
+    /// discarding any lexical errors recorded since.
+    pub(crate) fn rewind(&mut self, checkpoint: TokenSourceCheckpoint) {
+        self.cursor = checkpoint.cursor;
+        self.errors.truncate(checkpoint.errors_len);
+    }
+
+    /// Consumes the source, returning the lexical errors recorded so far.
+    pub(crate) fn finish(self) -> Vec<LexicalError> {
+        self.errors
+    }
+}